@@ -1,6 +1,18 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-pub use self::nft_minting_machine::{NFTMintingMachine, Error};
+pub use self::nft_minting_machine::{NFTMintingMachine, Error, Minted, Transfer, AdminChanged};
+pub use self::nft_minting_machine::{MintStatus, Approval, ApprovalForAll, CodeUpgraded};
+pub use self::nft_minting_machine::{OracleSetup, SupplyCheckpoint, Burned, AdminForceTransfer};
+pub use self::nft_minting_machine::{NFTMintingMachineRef, ON_NFT_RECEIVED, ON_MINT};
+pub use self::nft_minting_machine::{ROLE_MINTER, ROLE_PAUSER, ROLE_METADATA, ROLE_LOCKER};
+pub use self::nft_minting_machine::{MAX_LOOKUP_SIZE, MAX_NAME_LENGTH, DEFAULT_MAX_BATCH};
+pub use self::nft_minting_machine::{MAX_PREFIX_LENGTH, MAX_OPERATORS, MAX_PAGE_SIZE};
+pub use self::nft_minting_machine::MAX_ATTRIBUTES;
+pub use self::nft_minting_machine::{STORAGE_VERSION, RARITY_WEIGHTS, BPS_DENOMINATOR};
+pub use self::nft_minting_machine::RARITY_ATTRIBUTE;
+pub use self::nft_minting_machine::{
+    INTERFACE_NFT, INTERFACE_METADATA, INTERFACE_ENUMERABLE, INTERFACE_ROYALTY,
+};
 
 /// The `nft_minting_machine` module defines a smart contract for minting NFTs using an oracle
 /// token that tracks the current NFT index. The contract provides three primary actions:
@@ -9,18 +21,27 @@ pub use self::nft_minting_machine::{NFTMintingMachine, Error};
 /// - `get_oracle_data`: Returns the current state of the oracle (the NFT mint counter).
 #[ink::contract]
 mod nft_minting_machine {
-    use ink::storage::Mapping;
-    use ink::prelude::string::{String, ToString};
+    use ink::storage::{Lazy, Mapping};
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::env::hash::{Blake2x256, Keccak256};
+    use ink::env::call::{build_call, ExecutionInput, Selector};
 
     /// Represents an NFT record stored on-chain.
     /// Each NFT contains:
     /// - `token_name`: The generated name (including the incremented index).
     /// - `owner`: The AccountId of the minter.
+    /// - `metadata_uri`: The URI of the off-chain metadata (empty when none was provided).
+    /// - `minted_at`: The block timestamp at which the NFT was minted.
+    /// - `transfer_count`: The number of times the NFT has changed hands since it was minted.
     #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct Nft {
         token_name: String,
         owner: AccountId,
+        metadata_uri: String,
+        minted_at: Timestamp,
+        transfer_count: u32,
     }
 
     impl Nft {
@@ -33,14 +54,53 @@ mod nft_minting_machine {
         pub fn owner(&self) -> &AccountId {
             &self.owner
         }
+
+        /// Returns the metadata URI stored for the NFT, empty if it was minted without one.
+        pub fn metadata_uri(&self) -> &String {
+            &self.metadata_uri
+        }
+
+        /// Returns the block timestamp at which the NFT was minted.
+        pub fn minted_at(&self) -> Timestamp {
+            self.minted_at
+        }
+
+        /// Returns the number of times the NFT has changed hands since it was minted.
+        pub fn transfer_count(&self) -> u32 {
+            self.transfer_count
+        }
     }
 
-    /// OracleData holds the current NFT counter.
+    /// OracleData holds the current NFT counter along with the rest of the collection status.
     /// This is returned by the `get_oracle_data` function.
     #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct OracleData {
+        /// The oracle mint counter. It is not an NFT index: the NFT minted at counter `n` gets
+        /// index `n - 1 + start_index` (see `token_index`), and the counter may also sit past a
+        /// reserve or at the start given to `setup_oracle_from` or `reset_oracle`.
         pub current_index: u64,
+        /// Whether the oracle has been set up, i.e. whether minting is live.
+        pub setup: bool,
+        /// The maximum number of NFTs that can be minted, or `None` if unlimited.
+        pub max_supply: Option<u64>,
+        /// The number of NFTs currently in existence.
+        pub total_supply: u64,
+    }
+
+    /// MintStatus summarizes the restrictions that currently apply to minting for the caller.
+    /// This is returned by the `mint_status` function.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MintStatus {
+        /// Whether minting has been paused by the admin.
+        pub paused: bool,
+        /// Whether the current block is inside the timestamp and block-number mint windows.
+        pub within_window: bool,
+        /// How many more NFTs the caller may mint for itself, or `None` if there is no limit.
+        pub remaining_for_caller: Option<u32>,
+        /// Whether the maximum supply has been minted.
+        pub sold_out: bool,
     }
 
     /// Defines custom error types for the contract.
@@ -54,29 +114,511 @@ mod nft_minting_machine {
         OracleAlreadySet = 1,
         /// The oracle has not been initialized yet.
         OracleNotSetup = 2,
-        /// A counter overflow occurred.
+        /// When the NFT counter would overflow `u64`, independently of any maximum supply.
         CounterOverflow = 3,
         /// When an NFT with the specified index cannot be found.
         NFTNotFound = 4,
         /// When the caller is not the owner of the NFT.
         NotOwner = 5,
+        /// When minting would exceed the configured maximum supply.
+        MaxSupplyReached = 6,
+        /// When the caller is neither the owner nor approved to transfer the NFT.
+        NotApproved = 7,
+        /// When minting is attempted while the contract is paused.
+        Paused = 8,
+        /// When the value transferred with a mint is below the mint price.
+        InsufficientPayment = 9,
+        /// When a transfer of native tokens out of the contract fails.
+        TransferFailed = 10,
+        /// When the contract balance is too low for the requested withdrawal.
+        InsufficientBalance = 11,
+        /// When the caller has already minted the maximum allowed per account.
+        MintLimitReached = 12,
+        /// When minting is restricted to the allowlist and the caller is not on it.
+        NotAllowlisted = 13,
+        /// When a Merkle proof does not lead to the configured allowlist root.
+        InvalidProof = 14,
+        /// When minting is attempted outside the configured mint window.
+        MintNotActive = 15,
+        /// When a transfer or approval is attempted on a soulbound collection.
+        NonTransferable = 16,
+        /// When a royalty rate exceeds 10000 basis points (100%).
+        InvalidRoyalty = 17,
+        /// When metadata is changed after it has been frozen.
+        MetadataFrozen = 18,
+        /// When a contract recipient does not accept an NFT sent with `safe_transfer`.
+        TransferRejected = 19,
+        /// When an operation requires that no NFTs currently exist.
+        CollectionNotEmpty = 20,
+        /// When more reserved NFTs are requested than remain in the reserved range.
+        ReservedExhausted = 21,
+        /// When a mint authorization signature was not produced by the authorizer.
+        InvalidSignature = 22,
+        /// When a mint authorization nonce has already been used.
+        NonceUsed = 23,
+        /// When a call is given more items than it accepts.
+        TooManyItems = 24,
+        /// When a new token name exceeds `MAX_NAME_LENGTH` bytes.
+        NameTooLong = 25,
+        /// When an NFT would be minted or transferred to the all-zero account.
+        ZeroAddress = 26,
+        /// When an edition has no NFTs left to mint.
+        EditionSoldOut = 27,
+        /// When a locked NFT is transferred or burned.
+        TokenLocked = 28,
+        /// When a royalty rate exceeds the collection's maximum royalty rate.
+        RoyaltyTooHigh = 29,
+        /// When price tier thresholds are not strictly increasing.
+        InvalidPriceTiers = 30,
+        /// When an account mints again before its mint cooldown has elapsed.
+        CooldownActive = 31,
+        /// When the caller has no delegated mint allowance left.
+        NoAllowance = 32,
+        /// When `migrate` is called on storage that is already at the current version.
+        AlreadyMigrated = 33,
+        /// When replacing the contract code with `set_code` fails.
+        UpgradeFailed = 34,
+        /// When a new maximum supply doesn't raise the current one.
+        InvalidCap = 35,
     }
 
     /// A type alias for the contract's result type.
     pub type Result<T> = core::result::Result<T, Error>;
 
+    /// The number of basis points that make up 100%.
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+
+    /// The chance of each rarity tier assigned by `mint_with_seed`, in basis points, from the
+    /// most common tier 0 to the rarest. The weights add up to `BPS_DENOMINATOR`.
+    pub const RARITY_WEIGHTS: [u16; 4] = [6_000, 2_500, 1_000, 500];
+
+    /// The trait key under which `mint_with_seed` stores the rarity tier, as a decimal string.
+    /// It is assigned at mint and cannot be changed with `set_attribute`.
+    pub const RARITY_ATTRIBUTE: &str = "rarity";
+
+    /// Interface ID of the core ownership, transfer and approval messages: the XOR of the
+    /// selectors of `balance_of`, `owner_of`, `transfer_nft`, `transfer_from`, `safe_transfer`,
+    /// `approve`, `get_approved`, `set_approval_for_all` and `is_approved_for_all`.
+    pub const INTERFACE_NFT: [u8; 4] = interface_id(&[
+        ink::selector_bytes!("balance_of"),
+        ink::selector_bytes!("owner_of"),
+        ink::selector_bytes!("transfer_nft"),
+        ink::selector_bytes!("transfer_from"),
+        ink::selector_bytes!("safe_transfer"),
+        ink::selector_bytes!("approve"),
+        ink::selector_bytes!("get_approved"),
+        ink::selector_bytes!("set_approval_for_all"),
+        ink::selector_bytes!("is_approved_for_all"),
+    ]);
+    /// Interface ID of the metadata messages: the XOR of the selectors of `name`, `symbol` and
+    /// `token_uri`.
+    pub const INTERFACE_METADATA: [u8; 4] = interface_id(&[
+        ink::selector_bytes!("name"),
+        ink::selector_bytes!("symbol"),
+        ink::selector_bytes!("token_uri"),
+    ]);
+    /// Interface ID of the enumeration messages: the XOR of the selectors of `total_supply`,
+    /// `tokens_of_owner` and `tokens_paginated`.
+    pub const INTERFACE_ENUMERABLE: [u8; 4] = interface_id(&[
+        ink::selector_bytes!("total_supply"),
+        ink::selector_bytes!("tokens_of_owner"),
+        ink::selector_bytes!("tokens_paginated"),
+    ]);
+    /// Interface ID of the royalty messages: the selector of `royalty_info`.
+    pub const INTERFACE_ROYALTY: [u8; 4] = interface_id(&[ink::selector_bytes!("royalty_info")]);
+
+    /// The interface IDs reported by `supports_interface`.
+    const SUPPORTED_INTERFACES: [[u8; 4]; 4] =
+        [INTERFACE_NFT, INTERFACE_METADATA, INTERFACE_ENUMERABLE, INTERFACE_ROYALTY];
+
+    /// Selector of the `on_nft_received(operator, from, index, data)` message called on contract
+    /// recipients by `safe_transfer`. Recipients accept an NFT by returning this same value.
+    pub const ON_NFT_RECEIVED: [u8; 4] = ink::selector_bytes!("on_nft_received");
+
+    /// Selector of the `on_mint(index, owner)` message called on the observer after each mint.
+    pub const ON_MINT: [u8; 4] = ink::selector_bytes!("on_mint");
+
+    /// Role allowing an account to mint with `mint_to` and `mint_reserved`.
+    pub const ROLE_MINTER: u8 = 0;
+    /// Role allowing an account to `pause` and `unpause` minting.
+    pub const ROLE_PAUSER: u8 = 1;
+    /// Role allowing an account to manage metadata URIs, `reveal` and `freeze_metadata`.
+    pub const ROLE_METADATA: u8 = 2;
+    /// Role allowing an account, such as an escrow contract, to `lock` and `unlock` NFTs.
+    pub const ROLE_LOCKER: u8 = 3;
+
+    /// The maximum number of NFTs returned, and of indices scanned, by a single
+    /// `tokens_paginated` call, and the widest index range a single `range` call may span.
+    pub const MAX_PAGE_SIZE: u64 = 100;
+
+    /// The maximum number of indices or accounts accepted by a single `owners_of`, `get_nfts`,
+    /// `transfer_many`, `airdrop`, `add_many_to_allowlist` or `remove_many_from_allowlist` call.
+    pub const MAX_LOOKUP_SIZE: usize = 100;
+
+    /// The maximum number of operators an owner may approve at once, which bounds the work of
+    /// `revoke_all_approvals`.
+    pub const MAX_OPERATORS: usize = 16;
+
+    /// The maximum number of trait keys an NFT may have, which bounds the work of burning it.
+    pub const MAX_ATTRIBUTES: usize = 32;
+
+    /// The default maximum number of NFTs a single `mint_batch` call may mint.
+    pub const DEFAULT_MAX_BATCH: u32 = 50;
+
+    /// The storage version that `migrate` brings older storage to.
+    pub const STORAGE_VERSION: u16 = 1;
+
+    /// The maximum length, in bytes, of a token name, whether set with `rename` or generated
+    /// by a mint.
+    pub const MAX_NAME_LENGTH: usize = 64;
+
+    /// The maximum length, in bytes, of the token name prefix set with `new_with_prefix`.
+    pub const MAX_PREFIX_LENGTH: usize = 32;
+
+    /// The number of decimal digits of `u64::MAX`, and so the widest index padding accepted by
+    /// `new_with_padding`. Together with `MAX_PREFIX_LENGTH` it keeps plain `<prefix><index>`
+    /// names within `MAX_NAME_LENGTH`; longer names, like those of editions and combined NFTs,
+    /// are checked when they are generated.
+    const MAX_INDEX_DIGITS: u8 = 20;
+
+    /// Checks that `account` is not the all-zero account, which no one can ever move NFTs from.
+    fn ensure_not_zero(account: AccountId) -> Result<()> {
+        if account == AccountId::from([0u8; 32]) {
+            return Err(Error::ZeroAddress);
+        }
+        Ok(())
+    }
+
+    /// Checks that `name` fits within `MAX_NAME_LENGTH` bytes.
+    fn ensure_name_length(name: &str) -> Result<()> {
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(Error::NameTooLong);
+        }
+        Ok(())
+    }
+
+    /// Derives the ID of an interface from the selectors of its messages by XOR-ing them
+    /// together, so the ID changes whenever a message is added, removed or renamed.
+    const fn interface_id(selectors: &[[u8; 4]]) -> [u8; 4] {
+        let mut id = [0u8; 4];
+        let mut i = 0;
+        while i < selectors.len() {
+            let mut byte = 0;
+            while byte < 4 {
+                id[byte] ^= selectors[i][byte];
+                byte += 1;
+            }
+            i += 1;
+        }
+        id
+    }
+
+    /// Picks the rarity tier selected by `seed`, using its first two bytes as a roll in basis
+    /// points against the cumulative `RARITY_WEIGHTS`.
+    fn rarity_tier(seed: [u8; 32]) -> u8 {
+        let roll = u16::from_le_bytes([seed[0], seed[1]]) % BPS_DENOMINATOR;
+        let mut threshold = 0u16;
+        for (tier, weight) in RARITY_WEIGHTS.iter().enumerate() {
+            threshold = threshold.saturating_add(*weight);
+            if roll < threshold {
+                return tier as u8;
+            }
+        }
+        (RARITY_WEIGHTS.len() - 1) as u8
+    }
+
+    /// Appends the decimal digits of `n` to `buf`, producing the same output as `n.to_string()`
+    /// without allocating an intermediate `String`.
+    fn push_u64(buf: &mut String, n: u64) {
+        push_u64_padded(buf, n, 0);
+    }
+
+    /// Same as `push_u64`, but left-pads the digits with zeros to at least `width` characters.
+    /// Longer numbers are never truncated.
+    fn push_u64_padded(buf: &mut String, n: u64, width: u8) {
+        // u64::MAX has 20 decimal digits.
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        let mut rest = n;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        for _ in digits.len() - start..usize::from(width) {
+            buf.push('0');
+        }
+        for &digit in &digits[start..] {
+            buf.push(char::from(digit));
+        }
+    }
+
+    //----------------------------------
+    // Events
+    //----------------------------------
+
+    /// Emitted when a new NFT is minted.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Minted {
+        /// The index assigned to the new NFT.
+        #[ink(topic)]
+        pub index: u64,
+        /// The account that owns the new NFT.
+        #[ink(topic)]
+        pub owner: AccountId,
+        /// The generated token name.
+        pub token_name: String,
+    }
+
+    /// Emitted when ownership of an NFT changes.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Transfer {
+        /// The previous owner of the NFT.
+        #[ink(topic)]
+        pub from: AccountId,
+        /// The new owner of the NFT.
+        #[ink(topic)]
+        pub to: AccountId,
+        /// The index of the transferred NFT.
+        #[ink(topic)]
+        pub index: u64,
+    }
+
+    /// Emitted when an owner approves an account to transfer one of its NFTs.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Approval {
+        /// The owner of the NFT.
+        #[ink(topic)]
+        pub owner: AccountId,
+        /// The account approved to transfer the NFT.
+        #[ink(topic)]
+        pub approved: AccountId,
+        /// The index of the NFT.
+        pub index: u64,
+    }
+
+    /// Emitted when an owner grants or revokes an operator for all of its NFTs.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ApprovalForAll {
+        /// The owner of the NFTs.
+        #[ink(topic)]
+        pub owner: AccountId,
+        /// The operator being granted or revoked.
+        #[ink(topic)]
+        pub operator: AccountId,
+        /// Whether the operator is now approved.
+        pub approved: bool,
+    }
+
+    /// Emitted when the admin replaces the contract code with `set_code`.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct CodeUpgraded {
+        /// The hash of the new contract code.
+        pub code_hash: Hash,
+    }
+
+    /// Emitted when an NFT is burned, right before the `Transfer` event to the zero account.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Burned {
+        /// The index of the burned NFT.
+        #[ink(topic)]
+        pub index: u64,
+        /// The owner of the NFT at the time it was burned.
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    /// Emitted when the admin moves an NFT with `admin_transfer`, right after its `Transfer` event.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct AdminForceTransfer {
+        /// The index of the moved NFT.
+        #[ink(topic)]
+        pub index: u64,
+        /// The owner the NFT was taken from.
+        #[ink(topic)]
+        pub from: AccountId,
+        /// The account the NFT was given to.
+        #[ink(topic)]
+        pub to: AccountId,
+    }
+
+    /// Emitted when admin rights are handed over to another account.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct AdminChanged {
+        /// The previous admin.
+        #[ink(topic)]
+        pub previous: AccountId,
+        /// The new admin.
+        #[ink(topic)]
+        pub new: AccountId,
+    }
+
+    /// Emitted by `checkpoint` as a cheap supply snapshot for indexers.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct SupplyCheckpoint {
+        /// The oracle counter at the time of the snapshot.
+        pub current_index: u64,
+        /// The number of NFTs in existence at the time of the snapshot.
+        pub total_supply: u64,
+        /// The block number of the snapshot.
+        pub block_number: BlockNumber,
+    }
+
+    /// Emitted when the oracle is set up and the collection becomes mintable.
+    #[ink(event)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct OracleSetup {
+        /// The admin who set up the oracle.
+        #[ink(topic)]
+        pub admin: AccountId,
+    }
+
     /// The `NFTMintingMachine` contract manages the minting of NFTs and tracks the minting state
     /// using a one-time oracle setup.
     #[ink(storage)]
     pub struct NFTMintingMachine {
         /// The administrator of the contract, typically the deployer.
         admin: AccountId,
+        /// The storage version, raised by `migrate`. It lives in its own cell, so storage written
+        /// before versioning, where the cell is missing, reads as version 0.
+        version: Lazy<u16>,
+        /// The account proposed as the next admin, awaiting its acceptance.
+        pending_admin: Option<AccountId>,
+        /// The set of `(account, role)` pairs granted by the admin.
+        roles: Mapping<(AccountId, u8), ()>,
         /// Flag indicating whether the oracle has been set up.
         oracle_setup: bool,
+        /// Flag indicating whether minting is currently halted by the admin.
+        paused: bool,
+        /// Flag indicating whether minted NFTs are bound to their owner and can never move.
+        soulbound: bool,
         /// The current NFT mint counter.
         oracle_index: u64,
+        /// The number of NFTs currently in existence (minted minus burned).
+        total_supply: u64,
+        /// The number of NFTs ever minted, which unlike the counter never restarts, so that
+        /// `reset_oracle` cannot lift the maximum supply.
+        minted_total: Lazy<u64>,
+        /// The number of distinct accounts currently holding at least one NFT.
+        distinct_owners: u64,
         /// A mapping from NFT index to the NFT record.
         nfts: Mapping<u64, Nft>,
+        /// A mapping from account to the number of NFTs it owns.
+        balances: Mapping<AccountId, u64>,
+        /// A mapping from `(owner, position)` to the index of the NFT held at that position.
+        owned_tokens: Mapping<(AccountId, u64), u64>,
+        /// A mapping from NFT index to its position in its owner's `owned_tokens` list.
+        owned_token_positions: Mapping<u64, u64>,
+        /// The index of the most recently minted NFT.
+        last_minted_index: u64,
+        /// The account that performed the most recent mint, or `None` before the first one.
+        last_minter: Option<AccountId>,
+        /// The set of NFTs that are temporarily prevented from moving.
+        locked: Mapping<u64, ()>,
+        /// A mapping from NFT index to the account approved to transfer it.
+        approvals: Mapping<u64, AccountId>,
+        /// The set of `(owner, operator)` pairs where the operator may transfer any of the owner's NFTs.
+        operator_approvals: Mapping<(AccountId, AccountId), ()>,
+        /// A mapping from owner to the operators it has approved, in approval order.
+        operators: Mapping<AccountId, Vec<AccountId>>,
+        /// The maximum number of NFTs that can be minted, or `None` for unlimited minting.
+        max_supply: Option<u64>,
+        /// The size of the index range `1..=reserved` set aside for `mint_reserved`.
+        reserved: u64,
+        /// The number of NFTs minted so far from the reserved range.
+        reserved_minted: u64,
+        /// The prefix of every generated token name, followed by the NFT index. It is read on
+        /// every mint, so it stays in the contract struct.
+        prefix: String,
+        /// The minimum number of digits of the index in generated token names, padded with
+        /// leading zeros. A width of 0 disables padding.
+        pad_width: u8,
+        /// The index given to the first NFT of the counter; later NFTs follow consecutively.
+        start_index: u64,
+        /// The base URI used to derive metadata URIs for NFTs minted without one. Rarely read
+        /// string fields are kept `Lazy` so that messages which don't need them skip loading them.
+        base_uri: Lazy<String>,
+        /// The URI of the collection-level metadata JSON read by marketplaces.
+        contract_uri: Lazy<String>,
+        /// The URI returned for every NFT until the collection is revealed (empty when unused).
+        placeholder_uri: Lazy<String>,
+        /// Flag indicating whether the real per-NFT metadata URIs have been revealed.
+        revealed: bool,
+        /// Flag indicating whether metadata has been permanently frozen by the admin.
+        metadata_frozen: bool,
+        /// The set of NFTs whose individual metadata has been permanently frozen.
+        frozen_tokens: Mapping<u64, ()>,
+        /// The account that receives royalties on secondary sales.
+        royalty_recipient: AccountId,
+        /// The royalty rate on secondary sales, in basis points.
+        royalty_bps: u16,
+        /// The highest royalty rate, in basis points, that may be configured.
+        max_royalty_bps: u16,
+        /// Per-NFT royalty recipients and rates that override the collection default.
+        token_royalties: Mapping<u64, (AccountId, u16)>,
+        /// The collection-level name shown by explorers.
+        collection_name: Lazy<String>,
+        /// The collection-level ticker symbol shown by explorers.
+        collection_symbol: Lazy<String>,
+        /// The native token amount that must be paid for each minted NFT.
+        price: Balance,
+        /// `(threshold, price)` pairs sorted by threshold. Once `threshold` NFTs have been
+        /// minted, `price` replaces the prices of all lower tiers.
+        price_tiers: Vec<(u64, Balance)>,
+        /// The platform fee charged on top of the price of each NFT minted for the caller.
+        platform_fee: Balance,
+        /// The account that receives the platform fee of every mint.
+        treasury: AccountId,
+        /// The maximum number of NFTs a single account may mint, or `None` for no limit.
+        per_account_limit: Option<u32>,
+        /// The maximum number of NFTs a single `mint_batch` call may mint.
+        max_batch: u32,
+        /// A mapping from account to the number of NFTs it has minted for itself.
+        minted_by: Mapping<AccountId, u32>,
+        /// The minimum time between two mints of the same account, or 0 for no cooldown.
+        cooldown: Timestamp,
+        /// A mapping from account to the block timestamp of its most recent mint for itself.
+        last_mint_at: Mapping<AccountId, Timestamp>,
+        /// A mapping from partner account to the NFTs it may still mint with `delegated_mint`.
+        mint_allowance: Mapping<AccountId, u32>,
+        /// Flag indicating whether only allowlisted accounts may mint for themselves.
+        allowlist_only: bool,
+        /// The set of accounts allowed to mint while `allowlist_only` is enabled.
+        allowlist: Mapping<AccountId, ()>,
+        /// The root of a Merkle tree of allowlisted accounts, checked by `mint_with_proof`.
+        allowlist_root: Option<[u8; 32]>,
+        /// The timestamp from which minting opens, or `None` for no lower bound.
+        mint_start: Option<Timestamp>,
+        /// The timestamp at which minting closes, or `None` for no upper bound.
+        mint_end: Option<Timestamp>,
+        /// The block number from which minting opens, or `None` for no lower bound.
+        mint_start_block: Option<BlockNumber>,
+        /// The block number at which minting closes, or `None` for no upper bound.
+        mint_end_block: Option<BlockNumber>,
+        /// The account whose ECDSA signatures authorize `mint_with_signature`.
+        authorizer: AccountId,
+        /// The set of authorization nonces already consumed by `mint_with_signature`.
+        used_nonces: Mapping<u64, ()>,
+        /// The contract notified through `on_mint` after each mint, if any.
+        observer: Option<AccountId>,
+        /// A mapping from `(NFT index, trait key)` to the trait value.
+        attributes: Mapping<(u64, String), String>,
+        /// A mapping from NFT index to the trait keys set on it, so they can be cleared on burn.
+        attribute_keys: Mapping<u64, Vec<String>>,
+        /// A mapping from edition id to the number of NFTs of that edition left to mint.
+        editions: Mapping<u32, u32>,
     }
 
     //----------------------------------
@@ -87,9 +629,67 @@ mod nft_minting_machine {
         fn default() -> Self {
             Self {
                 admin: AccountId::from([0u8; 32]),
+                version: Lazy::new(),
+                pending_admin: None,
+                roles: Mapping::default(),
                 oracle_setup: false,
+                paused: false,
+                soulbound: false,
                 oracle_index: 0,
+                total_supply: 0,
+                minted_total: Lazy::new(),
+                distinct_owners: 0,
                 nfts: Mapping::default(),
+                balances: Mapping::default(),
+                owned_tokens: Mapping::default(),
+                owned_token_positions: Mapping::default(),
+                last_minted_index: 0,
+                last_minter: None,
+                locked: Mapping::default(),
+                approvals: Mapping::default(),
+                operator_approvals: Mapping::default(),
+                operators: Mapping::default(),
+                max_supply: None,
+                reserved: 0,
+                pad_width: 0,
+                start_index: 1,
+                reserved_minted: 0,
+                prefix: String::new(),
+                base_uri: Lazy::new(),
+                contract_uri: Lazy::new(),
+                placeholder_uri: Lazy::new(),
+                revealed: false,
+                metadata_frozen: false,
+                frozen_tokens: Mapping::default(),
+                royalty_recipient: AccountId::from([0u8; 32]),
+                royalty_bps: 0,
+                max_royalty_bps: BPS_DENOMINATOR,
+                token_royalties: Mapping::default(),
+                collection_name: Lazy::new(),
+                collection_symbol: Lazy::new(),
+                price: 0,
+                price_tiers: Vec::new(),
+                platform_fee: 0,
+                treasury: AccountId::from([0u8; 32]),
+                per_account_limit: None,
+                max_batch: DEFAULT_MAX_BATCH,
+                minted_by: Mapping::default(),
+                cooldown: 0,
+                last_mint_at: Mapping::default(),
+                mint_allowance: Mapping::default(),
+                allowlist_only: false,
+                allowlist: Mapping::default(),
+                allowlist_root: None,
+                mint_start: None,
+                mint_end: None,
+                mint_start_block: None,
+                mint_end_block: None,
+                authorizer: AccountId::from([0u8; 32]),
+                used_nonces: Mapping::default(),
+                observer: None,
+                attributes: Mapping::default(),
+                attribute_keys: Mapping::default(),
+                editions: Mapping::default(),
             }
         }
     }
@@ -102,101 +702,2506 @@ mod nft_minting_machine {
         /// Constructor: Initializes the contract with the deployer as the admin.
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {
+            let mut contract = Self {
                 admin: Self::env().caller(),
+                prefix: String::from("NFT #"),
                 ..Default::default()
+            };
+            // `Lazy` fields live outside the contract struct, so they are written explicitly.
+            contract.base_uri.set(&String::new());
+            contract.contract_uri.set(&String::new());
+            contract.placeholder_uri.set(&String::new());
+            contract.collection_name.set(&String::new());
+            contract.collection_symbol.set(&String::new());
+            contract
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and caps the
+        /// number of NFTs that can ever be minted at `max_supply`.
+        #[ink(constructor)]
+        pub fn new_with_cap(max_supply: u64) -> Self {
+            Self {
+                max_supply: Some(max_supply),
+                ..Self::new()
+            }
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and charges
+        /// `price` in the native token for each NFT minted by `mint_token` and its variants.
+        #[ink(constructor)]
+        pub fn new_with_price(price: Balance) -> Self {
+            Self {
+                price,
+                ..Self::new()
+            }
+        }
+
+        /// Constructor: Same as `new_with_price`, but additionally charges `platform_fee` per
+        /// NFT, which is forwarded to `treasury` with each mint while the price stays in the
+        /// contract.
+        ///
+        /// # Errors
+        /// - Returns `Error::ZeroAddress` if `treasury` is the all-zero account.
+        #[ink(constructor)]
+        pub fn new_with_platform_fee(
+            price: Balance,
+            platform_fee: Balance,
+            treasury: AccountId,
+        ) -> Result<Self> {
+            ensure_not_zero(treasury)?;
+            Ok(Self {
+                price,
+                platform_fee,
+                treasury,
+                ..Self::new()
+            })
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and a price that
+        /// rises with the supply. Each `(threshold, price)` tier applies once the NFT counter has
+        /// reached `threshold`; minting is free below the lowest threshold.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidPriceTiers` if the thresholds are not strictly increasing.
+        #[ink(constructor)]
+        pub fn new_with_price_tiers(price_tiers: Vec<(u64, Balance)>) -> Result<Self> {
+            if price_tiers.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return Err(Error::InvalidPriceTiers);
+            }
+            Ok(Self {
+                price_tiers,
+                ..Self::new()
+            })
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and allows each
+        /// account to mint at most `limit` NFTs for itself.
+        #[ink(constructor)]
+        pub fn new_with_mint_limit(limit: u32) -> Self {
+            Self {
+                per_account_limit: Some(limit),
+                ..Self::new()
+            }
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin for a soulbound
+        /// collection, whose NFTs can be minted and burned but never transferred or approved.
+        #[ink(constructor)]
+        pub fn new_soulbound() -> Self {
+            Self {
+                soulbound: true,
+                ..Self::new()
+            }
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and reports a
+        /// royalty of `bps` basis points payable to `recipient` on secondary sales.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidRoyalty` if `bps` exceeds 10000.
+        #[ink(constructor)]
+        pub fn new_with_royalty(recipient: AccountId, bps: u16) -> Result<Self> {
+            Self::new_with_royalty_cap(recipient, bps, BPS_DENOMINATOR)
+        }
+
+        /// Constructor: Same as `new_with_royalty`, but additionally limits every royalty rate
+        /// configured for the collection, including `bps`, to at most `max_bps` basis points.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidRoyalty` if `bps` or `max_bps` exceeds 10000.
+        /// - Returns `Error::RoyaltyTooHigh` if `bps` exceeds `max_bps`.
+        #[ink(constructor)]
+        pub fn new_with_royalty_cap(recipient: AccountId, bps: u16, max_bps: u16) -> Result<Self> {
+            if bps > BPS_DENOMINATOR || max_bps > BPS_DENOMINATOR {
+                return Err(Error::InvalidRoyalty);
+            }
+            if bps > max_bps {
+                return Err(Error::RoyaltyTooHigh);
+            }
+            Ok(Self {
+                royalty_recipient: recipient,
+                royalty_bps: bps,
+                max_royalty_bps: max_bps,
+                ..Self::new()
+            })
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and labels the
+        /// collection with `name` and `symbol`.
+        #[ink(constructor)]
+        pub fn new_with_collection(name: String, symbol: String) -> Self {
+            let mut contract = Self::new();
+            contract.collection_name.set(&name);
+            contract.collection_symbol.set(&symbol);
+            contract
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and sets aside
+        /// indices `1..=reserved` for the team. Those are minted with `mint_reserved`, while
+        /// public numbering starts after the reserved block.
+        #[ink(constructor)]
+        pub fn new_with_reserve(reserved: u64) -> Self {
+            Self {
+                reserved,
+                ..Self::new()
+            }
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and names every
+        /// NFT `<prefix><counter>` instead of the default "NFT #<counter>".
+        ///
+        /// # Errors
+        /// - Returns `Error::NameTooLong` if `prefix` exceeds `MAX_PREFIX_LENGTH` bytes.
+        #[ink(constructor)]
+        pub fn new_with_prefix(prefix: String) -> Result<Self> {
+            if prefix.len() > MAX_PREFIX_LENGTH {
+                return Err(Error::NameTooLong);
+            }
+            Ok(Self {
+                prefix,
+                ..Self::new()
+            })
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and pads the
+        /// index in every generated token name with zeros to at least `pad_width` digits,
+        /// e.g. "NFT #0001" for a width of 4.
+        ///
+        /// # Errors
+        /// - Returns `Error::NameTooLong` if `pad_width` exceeds the 20 digits of `u64::MAX`.
+        #[ink(constructor)]
+        pub fn new_with_padding(pad_width: u8) -> Result<Self> {
+            if pad_width > MAX_INDEX_DIGITS {
+                return Err(Error::NameTooLong);
+            }
+            Ok(Self {
+                pad_width,
+                ..Self::new()
+            })
+        }
+
+        /// Constructor: Initializes the contract with the deployer as the admin and gives the
+        /// first minted NFT index `start_index` instead of 1, e.g. 0 for zero-based integrations.
+        #[ink(constructor)]
+        pub fn new_with_start_index(start_index: u64) -> Self {
+            Self {
+                start_index,
+                ..Self::new()
             }
         }
 
-        /// Transfers ownership of a minted NFT to a new owner.
+        /// Transfers ownership of a minted NFT to a new owner and emits a `Transfer` event.
+        /// Any approval on the NFT is cleared, so a previously approved spender cannot move it.
         ///
         /// # Arguments
         /// - `nft_index`: The index of the NFT to transfer.
         /// - `new_owner`: The `AccountId` of the new owner.
         ///
         /// # Errors
+        /// - Returns `Error::NonTransferable` if the collection is soulbound.
         /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
         /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::ZeroAddress` if `new_owner` is the all-zero account.
+        /// - Returns `Error::TokenLocked` if the NFT is locked.
         #[ink(message)]
         pub fn transfer_nft(&mut self, nft_index: u64, new_owner: AccountId) -> Result<()> {
-            let mut nft = self.nfts.get(nft_index).ok_or(Error::NFTNotFound)?;
-            if self.env().caller() != nft.owner {
-                return Err(Error::NotOwner);
+            self.ensure_transferable()?;
+            ensure_not_zero(new_owner)?;
+            let nft = self.ensure_owner(nft_index)?;
+            self.ensure_unlocked(nft_index)?;
+            self.move_token(nft_index, nft, new_owner);
+            Ok(())
+        }
+
+        /// **Transfer Many**
+        ///
+        /// Transfers every NFT in `indices` to `to`, emitting a `Transfer` event for each.
+        /// Ownership of all NFTs is verified before any is moved, so either all of them are
+        /// transferred or none is.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` indices are given.
+        /// - Returns `Error::NotOwner` if the caller doesn't own one of the NFTs, or an index
+        ///   is listed twice.
+        /// - Returns any other error `transfer_nft` can return.
+        #[ink(message)]
+        pub fn transfer_many(&mut self, indices: Vec<u64>, to: AccountId) -> Result<()> {
+            if indices.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            self.ensure_transferable()?;
+            ensure_not_zero(to)?;
+            let mut nfts = Vec::with_capacity(indices.len());
+            for (position, &index) in indices.iter().enumerate() {
+                let nft = self.ensure_owner(index)?;
+                if indices[..position].contains(&index) {
+                    return Err(Error::NotOwner);
+                }
+                self.ensure_unlocked(index)?;
+                nfts.push(nft);
+            }
+            for (index, nft) in indices.into_iter().zip(nfts) {
+                self.move_token(index, nft, to);
             }
-            nft.owner = new_owner;
-            self.nfts.insert(nft_index, &nft);
             Ok(())
         }
 
-        /// **Setup Oracle**
+        /// **Admin Transfer**
         ///
-        /// Initializes the oracle by enabling the minting policy.
-        /// This function is one‑time use and can only be called by the admin.
+        /// Moves the NFT at `index` to `to` regardless of its current owner, for recovering
+        /// stolen NFTs on a moderated collection. Unlike the regular transfers, this ignores
+        /// ownership, approvals, locks and the soulbound flag. Emits a `Transfer` event followed
+        /// by an `AdminForceTransfer` event.
+        /// This function can only be called by the admin.
         ///
         /// # Errors
         /// - Returns `Error::NotAdmin` if the caller is not the admin.
-        /// - Returns `Error::OracleAlreadySet` if the oracle has already been set up.
+        /// - Returns `Error::ZeroAddress` if `to` is the all-zero account.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
         #[ink(message)]
-        pub fn setup_oracle(&mut self) -> Result<()> {
-            if self.env().caller() != self.admin {
-                return Err(Error::NotAdmin);
+        pub fn admin_transfer(&mut self, index: u64, to: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            ensure_not_zero(to)?;
+            let nft = self.nfts.get(index).ok_or(Error::NFTNotFound)?;
+            let from = nft.owner;
+            self.move_token(index, nft, to);
+            self.env().emit_event(AdminForceTransfer { index, from, to });
+            Ok(())
+        }
+
+        /// **Safe Transfer**
+        ///
+        /// Transfers the NFT at `index` to `to` like `transfer_nft`. When `to` is a contract,
+        /// its `on_nft_received` message is called with the caller, the previous owner, the
+        /// index and `data`, and the transfer is reverted unless it returns `ON_NFT_RECEIVED`.
+        /// The hook cannot call back into this contract: re-entry is not allowed, so even a
+        /// query from the hook fails.
+        ///
+        /// # Errors
+        /// - Returns any error `transfer_nft` can return.
+        /// - Returns `Error::TransferRejected` if a contract recipient does not accept the NFT.
+        #[ink(message)]
+        pub fn safe_transfer(&mut self, to: AccountId, index: u64, data: Vec<u8>) -> Result<()> {
+            self.transfer_nft(index, to)?;
+            if !self.env().is_contract(&to) {
+                return Ok(());
             }
-            if self.oracle_setup {
-                return Err(Error::OracleAlreadySet);
+            let caller = self.env().caller();
+            let response = build_call::<Environment>()
+                .call(to)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ON_NFT_RECEIVED))
+                        .push_arg(caller)
+                        .push_arg(caller)
+                        .push_arg(index)
+                        .push_arg(data),
+                )
+                .returns::<[u8; 4]>()
+                .try_invoke();
+            match response {
+                Ok(Ok(value)) if value == ON_NFT_RECEIVED => Ok(()),
+                _ => Err(Error::TransferRejected),
             }
-            self.oracle_setup = true;
-            self.oracle_index = 0;
+        }
+
+        /// **Approve**
+        ///
+        /// Allows `spender` to transfer the NFT at `index` on the owner's behalf via
+        /// `transfer_from`. Only one spender can be approved per NFT at a time, and the
+        /// approval is cleared whenever the NFT changes hands. Emits an `Approval` event.
+        ///
+        /// # Errors
+        /// - Returns `Error::NonTransferable` if the collection is soulbound.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        #[ink(message)]
+        pub fn approve(&mut self, index: u64, spender: AccountId) -> Result<()> {
+            self.ensure_transferable()?;
+            let nft = self.ensure_owner(index)?;
+            self.approvals.insert(index, &spender);
+            self.env().emit_event(Approval {
+                owner: nft.owner,
+                approved: spender,
+                index,
+            });
             Ok(())
         }
 
-        /// **Mint Token**
+        /// **Get Approved**
         ///
-        /// Mints a new NFT. This function checks that the oracle has been set up, increments
-        /// the NFT counter, creates a new NFT with a token name like "NFT #<counter>",
-        /// and registers it with the caller as the owner.
+        /// Returns the account approved to transfer the NFT at `index`, if any.
+        #[ink(message)]
+        pub fn get_approved(&self, index: u64) -> Option<AccountId> {
+            self.approvals.get(index)
+        }
+
+        /// **Set Approval For All**
         ///
-        /// # Returns
-        /// - The NFT's token index.
+        /// Grants or revokes `operator` the right to transfer any NFT owned by the caller.
+        /// Revoking removes the storage entries instead of storing a negative marker.
+        /// An owner can have at most `MAX_OPERATORS` operators at once, so that
+        /// `revoke_all_approvals` stays bounded; re-approving a listed operator and revoking
+        /// never fail. Emits an `ApprovalForAll` event.
         ///
         /// # Errors
-        /// - Returns `Error::OracleNotSetup` if the oracle has not been initialized.
-        /// - Returns `Error::CounterOverflow` if incrementing the counter would overflow.
+        /// - Returns `Error::TooManyItems` if `operator` is new and the caller already has
+        ///   `MAX_OPERATORS` operators.
         #[ink(message)]
-        pub fn mint_token(&mut self) -> Result<u64> {
-            if !self.oracle_setup {
-                return Err(Error::OracleNotSetup);
+        pub fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<()> {
+            let caller = self.env().caller();
+            let key = (caller, operator);
+            if approved && !self.operator_approvals.contains(key) {
+                let mut operators = self.operators.get(caller).unwrap_or_default();
+                if operators.len() >= MAX_OPERATORS {
+                    return Err(Error::TooManyItems);
+                }
+                operators.push(operator);
+                self.operators.insert(caller, &operators);
+                self.operator_approvals.insert(key, &());
+            } else if !approved && self.operator_approvals.contains(key) {
+                let mut operators = self.operators.get(caller).unwrap_or_default();
+                operators.retain(|listed| *listed != operator);
+                if operators.is_empty() {
+                    self.operators.remove(caller);
+                } else {
+                    self.operators.insert(caller, &operators);
+                }
+                self.operator_approvals.remove(key);
             }
-            // Increment the NFT counter
-            let next_index = self.oracle_index.checked_add(1).ok_or(Error::CounterOverflow)?;
-            self.oracle_index = next_index;
+            self.env().emit_event(ApprovalForAll {
+                owner: caller,
+                operator,
+                approved,
+            });
+            Ok(())
+        }
 
-            // Generate the NFT token name based on the new index
-            let mut token_name = String::from("NFT #");
-            let index_str = next_index.to_string(); // `to_string` now works because `ToString` is imported
-            token_name.push_str(&index_str);
+        /// **Revoke All Approvals**
+        ///
+        /// Revokes every operator the caller has approved with `set_approval_for_all`, emitting an
+        /// `ApprovalForAll` event for each. Approvals of single NFTs granted with `approve` are
+        /// left in place; they are cleared when the NFT moves or can be revoked one by one.
+        #[ink(message)]
+        pub fn revoke_all_approvals(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            for operator in self.operators.take(caller).unwrap_or_default() {
+                self.operator_approvals.remove((caller, operator));
+                self.env().emit_event(ApprovalForAll {
+                    owner: caller,
+                    operator,
+                    approved: false,
+                });
+            }
+            Ok(())
+        }
 
-            let nft = Nft {
-                token_name,
-                owner: self.env().caller(),
-            };
-            self.nfts.insert(next_index, &nft);
-            Ok(next_index)
+        /// **Is Approved For All**
+        ///
+        /// Returns whether `operator` may transfer any NFT owned by `owner`.
+        #[ink(message)]
+        pub fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.operator_approvals.contains((owner, operator))
         }
 
-        /// **Get Oracle Data**
+        /// **Transfer From**
         ///
-        /// Returns the current oracle data showing the state and the NFT mint counter.
+        /// Transfers the NFT at `index` from `from` to `to`. The caller must be the owner,
+        /// the approved spender of the NFT or an operator of the owner. Emits a `Transfer` event.
+        ///
+        /// # Errors
+        /// - Returns `Error::NonTransferable` if the collection is soulbound.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        /// - Returns `Error::NotOwner` if `from` is not the current owner.
+        /// - Returns `Error::NotApproved` if the caller is neither the owner nor approved.
+        /// - Returns `Error::ZeroAddress` if `to` is the all-zero account.
+        /// - Returns `Error::TokenLocked` if the NFT is locked.
         #[ink(message)]
-        pub fn get_oracle_data(&self) -> OracleData {
-            OracleData {
-                current_index: self.oracle_index,
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, index: u64) -> Result<()> {
+            self.ensure_transferable()?;
+            ensure_not_zero(to)?;
+            let nft = self.nfts.get(index).ok_or(Error::NFTNotFound)?;
+            if nft.owner != from {
+                return Err(Error::NotOwner);
+            }
+            let caller = self.env().caller();
+            if caller != from
+                && self.approvals.get(index) != Some(caller)
+                && !self.is_approved_for_all(from, caller)
+            {
+                return Err(Error::NotApproved);
             }
+            self.ensure_unlocked(index)?;
+            self.move_token(index, nft, to);
+            Ok(())
         }
 
-        /// (Optional) Retrieve a minted NFT by its index.
+        /// **Burn**
+        ///
+        /// Destroys the NFT at `index` along with its approval. A `Burned` event is emitted,
+        /// followed by a `Transfer` event to the zero account.
+        /// The oracle counter is not decreased, so burned indices are never reused.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::TokenLocked` if the NFT is locked.
         #[ink(message)]
-        pub fn get_nft(&self, index: u64) -> Option<Nft> {
-            self.nfts.get(index)
+        pub fn burn(&mut self, index: u64) -> Result<()> {
+            let nft = self.ensure_owner(index)?;
+            self.ensure_unlocked(index)?;
+            self.burn_token(index, nft.owner);
+            Ok(())
+        }
+
+        /// **Set Attribute**
+        ///
+        /// Sets the trait `key` of the NFT at `index` to `value`.
+        /// This function can only be called by the NFT's owner or the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        /// - Returns `Error::NotOwner` if the caller is neither the owner nor the admin.
+        /// - Returns `Error::MetadataFrozen` if metadata or the NFT has been frozen, or `key` is
+        ///   `RARITY_ATTRIBUTE`, which only `mint_with_seed` assigns.
+        /// - Returns `Error::TooManyItems` if `key` is new and the NFT already has
+        ///   `MAX_ATTRIBUTES` traits.
+        #[ink(message)]
+        pub fn set_attribute(&mut self, index: u64, key: String, value: String) -> Result<()> {
+            let nft = self.nfts.get(index).ok_or(Error::NFTNotFound)?;
+            let caller = self.env().caller();
+            if caller != nft.owner && caller != self.admin {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_token_mutable(index)?;
+            if key == RARITY_ATTRIBUTE {
+                return Err(Error::MetadataFrozen);
+            }
+            if !self.attributes.contains((index, key.clone())) {
+                let mut keys = self.attribute_keys.get(index).unwrap_or_default();
+                if keys.len() >= MAX_ATTRIBUTES {
+                    return Err(Error::TooManyItems);
+                }
+                keys.push(key.clone());
+                self.attribute_keys.insert(index, &keys);
+            }
+            self.attributes.insert((index, key), &value);
+            Ok(())
+        }
+
+        /// **Get Attribute**
+        ///
+        /// Returns the value of the trait `key` of the NFT at `index`, if set.
+        #[ink(message)]
+        pub fn get_attribute(&self, index: u64, key: String) -> Option<String> {
+            self.attributes.get((index, key))
+        }
+
+        /// **Lock**
+        ///
+        /// Prevents the NFT at `index` from being transferred or burned until it is unlocked.
+        /// This function can only be called by the admin or a `ROLE_LOCKER`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_LOCKER`.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn lock(&mut self, index: u64) -> Result<()> {
+            self.ensure_role(ROLE_LOCKER)?;
+            if !self.nfts.contains(index) {
+                return Err(Error::NFTNotFound);
+            }
+            self.locked.insert(index, &());
+            Ok(())
+        }
+
+        /// **Unlock**
+        ///
+        /// Allows the NFT at `index` to move again after a `lock`.
+        /// This function can only be called by the admin or a `ROLE_LOCKER`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_LOCKER`.
+        #[ink(message)]
+        pub fn unlock(&mut self, index: u64) -> Result<()> {
+            self.ensure_role(ROLE_LOCKER)?;
+            self.locked.remove(index);
+            Ok(())
+        }
+
+        /// **Last Mint**
+        ///
+        /// Returns the index of the most recently minted NFT and the account that minted it,
+        /// or `None` if nothing has been minted yet.
+        #[ink(message)]
+        pub fn last_mint(&self) -> Option<(u64, AccountId)> {
+            self.last_minter.map(|minter| (self.last_minted_index, minter))
+        }
+
+        /// **Is Locked**
+        ///
+        /// Returns whether the NFT at `index` is currently locked.
+        #[ink(message)]
+        pub fn is_locked(&self, index: u64) -> bool {
+            self.locked.contains(index)
+        }
+
+        /// **Rename**
+        ///
+        /// Replaces the token name of the NFT at `index` with `new_name`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::MetadataFrozen` if metadata or the NFT has been frozen.
+        /// - Returns `Error::NameTooLong` if `new_name` exceeds `MAX_NAME_LENGTH` bytes.
+        #[ink(message)]
+        pub fn rename(&mut self, index: u64, new_name: String) -> Result<()> {
+            let mut nft = self.ensure_owner(index)?;
+            self.ensure_token_mutable(index)?;
+            ensure_name_length(&new_name)?;
+            nft.token_name = new_name;
+            self.nfts.insert(index, &nft);
+            Ok(())
+        }
+
+        /// **Combine**
+        ///
+        /// Burns the NFTs at `a` and `b` and mints a new NFT to the caller in their place, named
+        /// after both inputs as "<name of a> + <name of b>". All checks are performed before
+        /// either NFT is burned, so the operation either fully succeeds or changes nothing.
+        ///
+        /// # Returns
+        /// - The index of the combined NFT.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if either NFT doesn't exist or `a` equals `b`.
+        /// - Returns `Error::NotOwner` if the caller doesn't own both NFTs.
+        /// - Returns `Error::TokenLocked` if either NFT is locked.
+        /// - Returns `Error::NameTooLong` if the combined name exceeds `MAX_NAME_LENGTH` bytes.
+        /// - Returns any error `mint_to` can return, apart from `Error::NotAdmin`.
+        #[ink(message)]
+        pub fn combine(&mut self, a: u64, b: u64) -> Result<u64> {
+            if a == b {
+                return Err(Error::NFTNotFound);
+            }
+            let first = self.ensure_owner(a)?;
+            let second = self.ensure_owner(b)?;
+            let caller = self.env().caller();
+            self.ensure_unlocked(a)?;
+            self.ensure_unlocked(b)?;
+            let counter = self.ensure_can_mint(1)?;
+            let index = self.token_index(counter)?;
+            let mut token_name = first.token_name;
+            token_name.push_str(" + ");
+            token_name.push_str(&second.token_name);
+            ensure_name_length(&token_name)?;
+
+            self.burn_token(a, caller);
+            self.burn_token(b, caller);
+            self.oracle_index = counter;
+            self.insert_token(
+                index,
+                Nft {
+                    token_name,
+                    owner: caller,
+                    metadata_uri: String::new(),
+                    minted_at: self.env().block_timestamp(),
+                    transfer_count: 0,
+                },
+            );
+            Ok(index)
+        }
+
+        /// **Transfer Admin**
+        ///
+        /// Hands admin rights over to `new_admin` and emits an `AdminChanged` event.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn transfer_admin(&mut self, new_admin: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            let previous = self.admin;
+            self.admin = new_admin;
+            self.pending_admin = None;
+            self.env().emit_event(AdminChanged {
+                previous,
+                new: new_admin,
+            });
+            Ok(())
+        }
+
+        /// **Propose Admin**
+        ///
+        /// Proposes `candidate` as the next admin. The handover only takes effect once the
+        /// candidate calls `accept_admin`; a new proposal replaces any pending one.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn propose_admin(&mut self, candidate: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            self.pending_admin = Some(candidate);
+            Ok(())
+        }
+
+        /// **Accept Admin**
+        ///
+        /// Completes a handover started with `propose_admin`, making the caller the admin
+        /// and emitting an `AdminChanged` event.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the pending admin.
+        #[ink(message)]
+        pub fn accept_admin(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.pending_admin != Some(caller) {
+                return Err(Error::NotAdmin);
+            }
+            let previous = self.admin;
+            self.admin = caller;
+            self.pending_admin = None;
+            self.env().emit_event(AdminChanged {
+                previous,
+                new: caller,
+            });
+            Ok(())
+        }
+
+        /// **Grant Role**
+        ///
+        /// Grants `role` (one of `ROLE_MINTER`, `ROLE_PAUSER`, `ROLE_METADATA` or `ROLE_LOCKER`)
+        /// to `account`.
+        /// The admin implicitly holds every role. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn grant_role(&mut self, account: AccountId, role: u8) -> Result<()> {
+            self.ensure_admin()?;
+            self.roles.insert((account, role), &());
+            Ok(())
+        }
+
+        /// **Revoke Role**
+        ///
+        /// Revokes `role` from `account`. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, account: AccountId, role: u8) -> Result<()> {
+            self.ensure_admin()?;
+            self.roles.remove((account, role));
+            Ok(())
+        }
+
+        /// **Has Role**
+        ///
+        /// Returns whether `account` holds `role`, either explicitly or by being the admin.
+        #[ink(message)]
+        pub fn has_role(&self, account: AccountId, role: u8) -> bool {
+            account == self.admin || self.roles.contains((account, role))
+        }
+
+        /// **Setup Oracle**
+        ///
+        /// Initializes the oracle by enabling the minting policy.
+        /// This function is one‑time use and can only be called by the admin.
+        /// It is a shortcut for `setup_oracle_from(0)`.
+        /// Emits an `OracleSetup` event.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::OracleAlreadySet` if the oracle has already been set up.
+        #[ink(message)]
+        pub fn setup_oracle(&mut self) -> Result<()> {
+            self.setup_oracle_from(0)
+        }
+
+        /// **Setup Oracle From**
+        ///
+        /// Same as `setup_oracle`, but starts the NFT counter at `start`, so the first mint
+        /// receives index `start + start_index`, e.g. `start + 1` by default. This lets a
+        /// migrated collection continue its numbering.
+        /// Emits an `OracleSetup` event.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::OracleAlreadySet` if the oracle has already been set up.
+        #[ink(message)]
+        pub fn setup_oracle_from(&mut self, start: u64) -> Result<()> {
+            self.ensure_admin()?;
+            if self.oracle_setup {
+                return Err(Error::OracleAlreadySet);
+            }
+            self.oracle_setup = true;
+            // Public numbering starts right after the reserved range, if any.
+            self.oracle_index = start.max(self.reserved);
+            self.env().emit_event(OracleSetup { admin: self.admin });
+            Ok(())
+        }
+
+        /// **Reset Oracle**
+        ///
+        /// Restarts the NFT counter at `start` for a new numbering series, so the next mint
+        /// receives index `start + start_index`. To avoid index collisions this is only
+        /// permitted while no NFTs exist, and the counter never restarts inside the reserved range.
+        /// Burned NFTs still count towards the maximum supply, so a reset never allows more NFTs
+        /// to be minted over the collection's lifetime than the cap.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::CollectionNotEmpty` if any NFT currently exists.
+        #[ink(message)]
+        pub fn reset_oracle(&mut self, start: u64) -> Result<()> {
+            self.ensure_admin()?;
+            if self.total_supply > 0 {
+                return Err(Error::CollectionNotEmpty);
+            }
+            // Public numbering never restarts inside the reserved range, like at setup.
+            self.oracle_index = start.max(self.reserved);
+            Ok(())
+        }
+
+        /// **Pause**
+        ///
+        /// Halts minting until `unpause` is called. Transfers remain allowed.
+        /// This function can only be called by the admin or a `ROLE_PAUSER`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_PAUSER`.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            self.ensure_role(ROLE_PAUSER)?;
+            self.paused = true;
+            Ok(())
+        }
+
+        /// **Unpause**
+        ///
+        /// Resumes minting after a `pause`. This function can only be called by the admin or a `ROLE_PAUSER`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_PAUSER`.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            self.ensure_role(ROLE_PAUSER)?;
+            self.paused = false;
+            Ok(())
+        }
+
+        /// **Set Allowlist Only**
+        ///
+        /// Enables or disables restricting `mint_token` and its variants to allowlisted accounts.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_allowlist_only(&mut self, enabled: bool) -> Result<()> {
+            self.ensure_admin()?;
+            self.allowlist_only = enabled;
+            Ok(())
+        }
+
+        /// **Add To Allowlist**
+        ///
+        /// Adds `who` to the allowlist. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn add_to_allowlist(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            self.allowlist.insert(who, &());
+            Ok(())
+        }
+
+        /// **Remove From Allowlist**
+        ///
+        /// Removes `who` from the allowlist. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn remove_from_allowlist(&mut self, who: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            self.allowlist.remove(who);
+            Ok(())
+        }
+
+        /// **Add Many To Allowlist**
+        ///
+        /// Adds every account in `accounts` to the allowlist.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` accounts are given.
+        #[ink(message)]
+        pub fn add_many_to_allowlist(&mut self, accounts: Vec<AccountId>) -> Result<()> {
+            self.ensure_admin()?;
+            if accounts.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            for who in accounts {
+                self.allowlist.insert(who, &());
+            }
+            Ok(())
+        }
+
+        /// **Remove Many From Allowlist**
+        ///
+        /// Removes every account in `accounts` from the allowlist.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` accounts are given.
+        #[ink(message)]
+        pub fn remove_many_from_allowlist(&mut self, accounts: Vec<AccountId>) -> Result<()> {
+            self.ensure_admin()?;
+            if accounts.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            for who in accounts {
+                self.allowlist.remove(who);
+            }
+            Ok(())
+        }
+
+        /// **Is Allowlisted**
+        ///
+        /// Returns whether `who` is on the allowlist. This reflects membership only, whether or
+        /// not minting is currently restricted with `set_allowlist_only`.
+        #[ink(message)]
+        pub fn is_allowlisted(&self, who: AccountId) -> bool {
+            self.allowlist.contains(who)
+        }
+
+        /// **Set Allowlist Root**
+        ///
+        /// Sets the root of the Merkle tree checked by `mint_with_proof`, or clears it with `None`.
+        /// Leaves are the Keccak-256 hash of an account, and each pair of nodes is hashed in
+        /// ascending order. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_allowlist_root(&mut self, root: Option<[u8; 32]>) -> Result<()> {
+            self.ensure_admin()?;
+            self.allowlist_root = root;
+            Ok(())
+        }
+
+        /// **Set Observer**
+        ///
+        /// Sets the contract whose `on_mint(index, owner)` message is called after each mint,
+        /// or clears it with `None`. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_observer(&mut self, observer: Option<AccountId>) -> Result<()> {
+            self.ensure_admin()?;
+            self.observer = observer;
+            Ok(())
+        }
+
+        /// **Observer**
+        ///
+        /// Returns the contract notified after each mint, if any.
+        #[ink(message)]
+        pub fn observer(&self) -> Option<AccountId> {
+            self.observer
+        }
+
+        /// **Set Edition Supply**
+        ///
+        /// Sets the number of NFTs of `edition` left to mint with `mint_edition`.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_edition_supply(&mut self, edition: u32, supply: u32) -> Result<()> {
+            self.ensure_admin()?;
+            self.editions.insert(edition, &supply);
+            Ok(())
+        }
+
+        /// **Increase Max Supply**
+        ///
+        /// Raises the maximum supply to `new_cap`. The cap can never be lowered, protecting
+        /// holders from a shrinking collection. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::InvalidCap` if the supply is unlimited or `new_cap` doesn't exceed
+        ///   the current maximum supply.
+        #[ink(message)]
+        pub fn increase_max_supply(&mut self, new_cap: u64) -> Result<()> {
+            self.ensure_admin()?;
+            match self.max_supply {
+                Some(cap) if new_cap > cap => {
+                    self.max_supply = Some(new_cap);
+                    Ok(())
+                }
+                _ => Err(Error::InvalidCap),
+            }
+        }
+
+        /// **Set Max Batch**
+        ///
+        /// Sets the maximum number of NFTs a single `mint_batch` call may mint.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_max_batch(&mut self, max_batch: u32) -> Result<()> {
+            self.ensure_admin()?;
+            self.max_batch = max_batch;
+            Ok(())
+        }
+
+        /// **Set Cooldown**
+        ///
+        /// Sets the minimum time that must pass between two mints of the same account for
+        /// itself. A cooldown of 0 disables the check.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_cooldown(&mut self, cooldown: Timestamp) -> Result<()> {
+            self.ensure_admin()?;
+            self.cooldown = cooldown;
+            Ok(())
+        }
+
+        /// **Set Mint Window**
+        ///
+        /// Restricts `mint_token` and its variants to block timestamps in `[start, end)`.
+        /// A `None` bound leaves that side of the window open.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_mint_window(
+            &mut self,
+            start: Option<Timestamp>,
+            end: Option<Timestamp>,
+        ) -> Result<()> {
+            self.ensure_admin()?;
+            self.mint_start = start;
+            self.mint_end = end;
+            Ok(())
+        }
+
+        /// **Set Mint Block Window**
+        ///
+        /// Restricts `mint_token` and its variants to block numbers in `[start, end)`.
+        /// A `None` bound leaves that side of the window open. Both this window and the
+        /// timestamp window set by `set_mint_window` must be open for minting to succeed.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_mint_block_window(
+            &mut self,
+            start: Option<BlockNumber>,
+            end: Option<BlockNumber>,
+        ) -> Result<()> {
+            self.ensure_admin()?;
+            self.mint_start_block = start;
+            self.mint_end_block = end;
+            Ok(())
+        }
+
+        /// **Set Authorizer**
+        ///
+        /// Sets the account whose signatures authorize `mint_with_signature`. For an ECDSA key
+        /// this is the BLAKE2-256 hash of its compressed public key, as derived by Substrate.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_authorizer(&mut self, authorizer: AccountId) -> Result<()> {
+            self.ensure_admin()?;
+            self.authorizer = authorizer;
+            Ok(())
+        }
+
+        /// **Checkpoint**
+        ///
+        /// Emits a `SupplyCheckpoint` event with the current oracle counter, total supply and
+        /// block number. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn checkpoint(&mut self) -> Result<()> {
+            self.ensure_admin()?;
+            self.env().emit_event(SupplyCheckpoint {
+                current_index: self.oracle_index,
+                total_supply: self.total_supply,
+                block_number: self.env().block_number(),
+            });
+            Ok(())
+        }
+
+        /// **Set Code**
+        ///
+        /// Replaces the contract code with the code uploaded under `code_hash`, keeping the
+        /// storage and account. Run `migrate` afterwards if the new code adds fields.
+        /// Emits a `CodeUpgraded` event.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::UpgradeFailed` if no code is stored under `code_hash`.
+        #[ink(message)]
+        pub fn set_code(&mut self, code_hash: Hash) -> Result<()> {
+            self.ensure_admin()?;
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| Error::UpgradeFailed)?;
+            self.env().emit_event(CodeUpgraded { code_hash });
+            Ok(())
+        }
+
+        /// **Migrate**
+        ///
+        /// Brings the storage up to `STORAGE_VERSION`, giving fields added since the stored version
+        /// their defaults. Each step runs once. Storage starts at version 0, both on a fresh
+        /// deployment and on one made before versioning, so the admin calls this once after
+        /// deploying and again after each upgrade with `set_code`. Since the root struct must
+        /// still decode after an upgrade, new fields have to live in their own `Lazy` or
+        /// `Mapping` cells, and steps here initialize those.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::AlreadyMigrated` if the storage is already at `STORAGE_VERSION`.
+        #[ink(message)]
+        pub fn migrate(&mut self) -> Result<()> {
+            self.ensure_admin()?;
+            let version = self.version();
+            if version >= STORAGE_VERSION {
+                return Err(Error::AlreadyMigrated);
+            }
+            if version < 1 {
+                // Version 1 writes the metadata and collection cells, which older storage lacks.
+                if self.base_uri.get().is_none() {
+                    self.base_uri.set(&String::new());
+                }
+                if self.contract_uri.get().is_none() {
+                    self.contract_uri.set(&String::new());
+                }
+                if self.placeholder_uri.get().is_none() {
+                    self.placeholder_uri.set(&String::new());
+                }
+                if self.collection_name.get().is_none() {
+                    self.collection_name.set(&String::new());
+                }
+                if self.collection_symbol.get().is_none() {
+                    self.collection_symbol.set(&String::new());
+                }
+            }
+            self.version.set(&STORAGE_VERSION);
+            Ok(())
+        }
+
+        /// **Version**
+        ///
+        /// Returns the storage version this contract has been migrated to, 0 if never migrated.
+        #[ink(message)]
+        pub fn version(&self) -> u16 {
+            self.version.get().unwrap_or(0)
+        }
+
+        /// **Withdraw**
+        ///
+        /// Sends `amount` of the collected mint fees to the admin.
+        /// This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::InsufficientBalance` if `amount` exceeds the contract balance.
+        /// - Returns `Error::TransferFailed` if the transfer to the admin fails.
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<()> {
+            self.ensure_admin()?;
+            if amount > self.env().balance() {
+                return Err(Error::InsufficientBalance);
+            }
+            self.env()
+                .transfer(self.admin, amount)
+                .map_err(|_| Error::TransferFailed)
+        }
+
+        /// **Collected**
+        ///
+        /// Returns the contract balance, i.e. the mint fees currently available to `withdraw`.
+        #[ink(message)]
+        pub fn collected(&self) -> Balance {
+            self.env().balance()
+        }
+
+        /// **Current Price**
+        ///
+        /// Returns the price of the next NFT, taken from the highest price tier whose threshold
+        /// the NFT counter has reached, or the flat mint price if no tier applies, plus the
+        /// platform fee. This is exactly what `mint_token` charges, so it can be queried before
+        /// a payable mint.
+        #[ink(message)]
+        pub fn current_price(&self) -> Balance {
+            self.price_at(self.oracle_index).saturating_add(self.platform_fee)
+        }
+
+        /// **Mint Token**
+        ///
+        /// Mints a new NFT. This function checks that the oracle has been set up, increments
+        /// the NFT counter, creates a new NFT with a token name like "NFT #<counter>" (or the
+        /// configured prefix followed by the counter),
+        /// and registers it with the caller as the owner. A `Minted` event is emitted on success.
+        /// The transferred value must cover the mint price, which is kept in the contract, while
+        /// any excess is refunded to the caller.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::InsufficientPayment` if the transferred value is below the price.
+        /// - Returns `Error::TransferFailed` if forwarding the platform fee or refunding the
+        ///   excess payment fails.
+        /// - Returns `Error::MintLimitReached` if the caller has reached the per-account limit.
+        /// - Returns `Error::CooldownActive` if the caller's mint cooldown hasn't elapsed yet.
+        /// - Returns `Error::NotAllowlisted` if minting is allowlist-only and the caller isn't listed.
+        /// - Returns `Error::MintNotActive` if the current block is outside the mint window.
+        /// - Returns `Error::OracleNotSetup` if the oracle has not been initialized.
+        /// - Returns `Error::Paused` if minting has been paused by the admin.
+        /// - Returns `Error::CounterOverflow` if incrementing the counter would overflow.
+        /// - Returns `Error::MaxSupplyReached` if the configured maximum supply has been minted.
+        #[ink(message, payable)]
+        pub fn mint_token(&mut self) -> Result<u64> {
+            self.ensure_allowlisted()?;
+            self.public_mint(1, |contract, caller| contract.mint_for(caller, String::new()))
+        }
+
+        /// **Mint With URI**
+        ///
+        /// Mints a new NFT exactly like `mint_token`, but stores `uri` as its metadata URI.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns any error `mint_token` can return.
+        #[ink(message, payable)]
+        pub fn mint_with_uri(&mut self, uri: String) -> Result<u64> {
+            self.ensure_allowlisted()?;
+            self.public_mint(1, |contract, caller| contract.mint_for(caller, uri))
+        }
+
+        /// **Mint With Seed**
+        ///
+        /// Mints a new NFT exactly like `mint_token` and assigns it a rarity tier drawn with
+        /// `RARITY_WEIGHTS`, stored as its `RARITY_ATTRIBUTE` trait. The draw hashes the block
+        /// timestamp, the caller and the index, so it is deterministic and NOT secure: a caller
+        /// can predict or influence the outcome by choosing when to mint.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns any error `mint_token` can return.
+        #[ink(message, payable)]
+        pub fn mint_with_seed(&mut self) -> Result<u64> {
+            self.ensure_allowlisted()?;
+            self.public_mint(1, |contract, caller| {
+                let index = contract.mint_for(caller, String::new())?;
+                let now = contract.env().block_timestamp();
+                let seed = contract
+                    .env()
+                    .hash_encoded::<Blake2x256, _>(&(now, caller, index));
+                let mut tier = String::new();
+                push_u64(&mut tier, u64::from(rarity_tier(seed)));
+                let key = String::from(RARITY_ATTRIBUTE);
+                contract.attribute_keys.insert(index, &Vec::from([key.clone()]));
+                contract.attributes.insert((index, key), &tier);
+                Ok(index)
+            })
+        }
+
+        /// **Rarity**
+        ///
+        /// Returns the rarity tier of the NFT at `index`, read from its `RARITY_ATTRIBUTE` trait,
+        /// or `None` if it wasn't minted with `mint_with_seed` or doesn't exist.
+        #[ink(message)]
+        pub fn rarity(&self, index: u64) -> Option<u8> {
+            self.attributes
+                .get((index, String::from(RARITY_ATTRIBUTE)))
+                .and_then(|tier| tier.parse().ok())
+        }
+
+        /// **Mint With Proof**
+        ///
+        /// Mints a new NFT exactly like `mint_token`, but proves the caller's allowlist membership
+        /// with a Merkle `proof` against the configured root instead of the on-chain allowlist.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidProof` if no root is set or the proof doesn't lead to it.
+        /// - Returns any other error `mint_token` can return.
+        #[ink(message, payable)]
+        pub fn mint_with_proof(&mut self, proof: Vec<[u8; 32]>) -> Result<u64> {
+            let root = self.allowlist_root.ok_or(Error::InvalidProof)?;
+            let caller = self.env().caller();
+            if self.merkle_root_of(caller, &proof) != root {
+                return Err(Error::InvalidProof);
+            }
+            self.public_mint(1, |contract, caller| contract.mint_for(caller, String::new()))
+        }
+
+        /// **Mint To**
+        ///
+        /// Mints a new NFT exactly like `mint_token`, but registers `recipient` as the owner
+        /// instead of the caller. This function can only be called by the admin or a `ROLE_MINTER`.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_MINTER`.
+        /// - Returns `Error::ZeroAddress` if `recipient` is the all-zero account.
+        /// - Returns any error `mint_token` can return.
+        #[ink(message)]
+        pub fn mint_to(&mut self, recipient: AccountId) -> Result<u64> {
+            self.ensure_role(ROLE_MINTER)?;
+            ensure_not_zero(recipient)?;
+            self.mint_for(recipient, String::new())
+        }
+
+        /// **Set Mint Allowance**
+        ///
+        /// Allows `account` to mint up to `allowance` NFTs with `delegated_mint`, replacing any
+        /// allowance it had before. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        #[ink(message)]
+        pub fn set_mint_allowance(&mut self, account: AccountId, allowance: u32) -> Result<()> {
+            self.ensure_admin()?;
+            if allowance == 0 {
+                self.mint_allowance.remove(account);
+            } else {
+                self.mint_allowance.insert(account, &allowance);
+            }
+            Ok(())
+        }
+
+        /// **Mint Allowance**
+        ///
+        /// Returns how many NFTs `account` may still mint with `delegated_mint`.
+        #[ink(message)]
+        pub fn mint_allowance(&self, account: AccountId) -> u32 {
+            self.mint_allowance.get(account).unwrap_or(0)
+        }
+
+        /// **Delegated Mint**
+        ///
+        /// Mints a new NFT to `to` on the admin's behalf, using up one NFT of the caller's
+        /// mint allowance granted with `set_mint_allowance`.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoAllowance` if the caller has no mint allowance left.
+        /// - Returns `Error::ZeroAddress` if `to` is the all-zero account.
+        /// - Returns any oracle, pause or supply error `mint_to` can return.
+        #[ink(message)]
+        pub fn delegated_mint(&mut self, to: AccountId) -> Result<u64> {
+            let caller = self.env().caller();
+            let allowance = self.mint_allowance(caller);
+            if allowance == 0 {
+                return Err(Error::NoAllowance);
+            }
+            ensure_not_zero(to)?;
+            self.ensure_can_mint(1)?;
+            // Spend the allowance before minting, so it is used up before any external call.
+            if allowance == 1 {
+                self.mint_allowance.remove(caller);
+            } else {
+                self.mint_allowance.insert(caller, &(allowance - 1));
+            }
+            self.mint_for(to, String::new())
+        }
+
+        /// **Mint With Signature**
+        ///
+        /// Mints a new NFT to `recipient` on behalf of the authorizer, so that anyone can submit
+        /// a mint the authorizer approved off-chain. `signature` is the authorizer's ECDSA
+        /// signature over the BLAKE2-256 hash of the SCALE-encoded `(contract, recipient, nonce)`,
+        /// where `contract` is this contract's account, so a signature can't be replayed on
+        /// another contract sharing the authorizer. Each `nonce` can be used only once.
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::NonceUsed` if `nonce` has already been used.
+        /// - Returns `Error::InvalidSignature` if the signature was not made by the authorizer.
+        /// - Returns any error `mint_to` can return, apart from `Error::NotAdmin`.
+        #[ink(message)]
+        pub fn mint_with_signature(
+            &mut self,
+            recipient: AccountId,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<u64> {
+            ensure_not_zero(recipient)?;
+            if self.used_nonces.contains(nonce) {
+                return Err(Error::NonceUsed);
+            }
+            let contract = self.env().account_id();
+            let message = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(contract, recipient, nonce));
+            let public_key = self
+                .env()
+                .ecdsa_recover(&signature, &message)
+                .map_err(|_| Error::InvalidSignature)?;
+            let signer = AccountId::from(self.env().hash_bytes::<Blake2x256>(&public_key));
+            if signer != self.authorizer {
+                return Err(Error::InvalidSignature);
+            }
+            self.ensure_can_mint(1)?;
+            // Record the nonce before minting, so it is spent before any external call is made.
+            self.used_nonces.insert(nonce, &());
+            self.mint_for(recipient, String::new())
+        }
+
+        /// **Mint Edition**
+        ///
+        /// Mints a new NFT of `edition` exactly like `mint_token`, and decrements the number of
+        /// NFTs left in that edition. The token name is the usual name followed by the edition,
+        /// like "NFT #<counter> (Edition <edition>)".
+        ///
+        /// # Returns
+        /// - The NFT's token index.
+        ///
+        /// # Errors
+        /// - Returns `Error::EditionSoldOut` if no NFTs of `edition` are left.
+        /// - Returns `Error::NameTooLong` if the edition name exceeds `MAX_NAME_LENGTH` bytes.
+        /// - Returns any other error `mint_token` can return.
+        #[ink(message, payable)]
+        pub fn mint_edition(&mut self, edition: u32) -> Result<u64> {
+            let remaining = self.editions.get(edition).unwrap_or(0);
+            if remaining == 0 {
+                return Err(Error::EditionSoldOut);
+            }
+            self.ensure_allowlisted()?;
+            let counter = self.ensure_can_mint(1)?;
+            let index = self.token_index(counter)?;
+            let mut token_name = self.prefix.clone();
+            push_u64_padded(&mut token_name, index, self.pad_width);
+            token_name.push_str(" (Edition ");
+            push_u64(&mut token_name, u64::from(edition));
+            token_name.push(')');
+            ensure_name_length(&token_name)?;
+            self.public_mint(1, |contract, caller| {
+                contract.oracle_index = counter;
+                contract.editions.insert(edition, &(remaining - 1));
+                let minted_at = contract.env().block_timestamp();
+                Ok(contract.insert_token(
+                    index,
+                    Nft {
+                        token_name,
+                        owner: caller,
+                        metadata_uri: String::new(),
+                        minted_at,
+                        transfer_count: 0,
+                    },
+                ))
+            })
+        }
+
+        /// **Edition Remaining**
+        ///
+        /// Returns how many NFTs of `edition` are left to mint.
+        #[ink(message)]
+        pub fn edition_remaining(&self, edition: u32) -> u32 {
+            self.editions.get(edition).unwrap_or(0)
+        }
+
+        /// **Mint Reserved**
+        ///
+        /// Mints `count` NFTs from the reserved range to `to`, in index order.
+        /// This function can only be called by the admin or a `ROLE_MINTER`.
+        ///
+        /// # Returns
+        /// - The indices of the minted NFTs, in minting order.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_MINTER`.
+        /// - Returns `Error::ZeroAddress` if `to` is the all-zero account.
+        /// - Returns `Error::OracleNotSetup` if the oracle has not been initialized.
+        /// - Returns `Error::Paused` if minting has been paused by the admin.
+        /// - Returns `Error::ReservedExhausted` if fewer than `count` reserved NFTs remain.
+        #[ink(message)]
+        pub fn mint_reserved(&mut self, to: AccountId, count: u32) -> Result<Vec<u64>> {
+            self.ensure_role(ROLE_MINTER)?;
+            ensure_not_zero(to)?;
+            self.ensure_can_mint(0)?;
+            if u64::from(count) > self.reserved_remaining() {
+                return Err(Error::ReservedExhausted);
+            }
+            // Walk the reserve counters, so that a `count` of zero mints nothing.
+            let first = self.reserved_minted.saturating_add(1);
+            let last = self.reserved_minted.saturating_add(u64::from(count));
+            self.token_index(last)?;
+            self.reserved_minted = last;
+            (first..=last)
+                .map(|counter| {
+                    let index = self.token_index(counter)?;
+                    Ok(self.create_token(index, to, String::new()))
+                })
+                .collect()
+        }
+
+        /// **Reserved Remaining**
+        ///
+        /// Returns how many NFTs of the reserved range have not been minted yet.
+        #[ink(message)]
+        pub fn reserved_remaining(&self) -> u64 {
+            self.reserved.saturating_sub(self.reserved_minted)
+        }
+
+        /// **Mint Batch**
+        ///
+        /// Mints `count` NFTs to the caller in a single call. All checks are performed up front,
+        /// so either every NFT is minted or none is. The transferred value must cover the mint
+        /// price of the whole batch, where each NFT is priced at the tier it falls in, and any
+        /// excess is refunded like in `mint_token`.
+        ///
+        /// # Returns
+        /// - The indices of the minted NFTs, in minting order.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if `count` exceeds the configured batch limit.
+        /// - Returns any error `mint_token` can return for the last NFT of the batch.
+        #[ink(message, payable)]
+        pub fn mint_batch(&mut self, count: u32) -> Result<Vec<u64>> {
+            if count > self.max_batch {
+                return Err(Error::TooManyItems);
+            }
+            self.ensure_allowlisted()?;
+            self.public_mint(count, |contract, caller| {
+                (0..count).map(|_| contract.mint_for(caller, String::new())).collect()
+            })
+        }
+
+        /// **Airdrop**
+        ///
+        /// Mints one NFT to each of `recipients`. All checks are performed up front, so either
+        /// every recipient receives an NFT or none does.
+        /// This function can only be called by the admin or a `ROLE_MINTER`.
+        ///
+        /// # Returns
+        /// - The indices of the minted NFTs, in the order of `recipients`.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` recipients are given.
+        /// - Returns any error `mint_to` can return for any recipient of the airdrop.
+        #[ink(message)]
+        pub fn airdrop(&mut self, recipients: Vec<AccountId>) -> Result<Vec<u64>> {
+            self.ensure_role(ROLE_MINTER)?;
+            if recipients.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            for recipient in &recipients {
+                ensure_not_zero(*recipient)?;
+            }
+            let count = u64::try_from(recipients.len()).map_err(|_| Error::CounterOverflow)?;
+            self.ensure_can_mint(count)?;
+            recipients
+                .into_iter()
+                .map(|recipient| self.mint_for(recipient, String::new()))
+                .collect()
+        }
+
+        /// **Admin**
+        ///
+        /// Returns the current admin account.
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+
+        /// **Is Oracle Setup**
+        ///
+        /// Returns whether the oracle has been set up, i.e. whether minting is live.
+        #[ink(message)]
+        pub fn is_oracle_setup(&self) -> bool {
+            self.oracle_setup
+        }
+
+        /// **Get Oracle Data**
+        ///
+        /// Returns the current oracle data showing the state and the NFT mint counter.
+        #[ink(message)]
+        pub fn get_oracle_data(&self) -> OracleData {
+            OracleData {
+                current_index: self.oracle_index,
+                setup: self.oracle_setup,
+                max_supply: self.max_supply,
+                total_supply: self.total_supply,
+            }
+        }
+
+        /// **Mint Status**
+        ///
+        /// Returns the restrictions that currently apply when the caller mints for itself, so a
+        /// front-end can tell why minting would fail without probing each condition.
+        #[ink(message)]
+        pub fn mint_status(&self) -> MintStatus {
+            let minted = self.minted_by.get(self.env().caller()).unwrap_or(0);
+            let remaining_for_caller =
+                self.per_account_limit.map(|limit| limit.saturating_sub(minted));
+            MintStatus {
+                paused: self.paused,
+                within_window: self.ensure_mint_window().is_ok(),
+                remaining_for_caller,
+                sold_out: matches!(self.max_supply, Some(cap) if self.oracle_index >= cap),
+            }
+        }
+
+        /// **Can Mint**
+        ///
+        /// Returns whether `who` could mint one NFT for itself right now, evaluating the setup,
+        /// pause, mint window, supply cap, per-account limit, cooldown and allowlist checks of
+        /// `mint_token`. Payment is not considered, as it depends on the transferred value.
+        #[ink(message)]
+        pub fn can_mint(&self, who: AccountId) -> bool {
+            if self.ensure_can_mint(1).is_err() || self.ensure_mint_window().is_err() {
+                return false;
+            }
+            let minted = self.minted_by.get(who).unwrap_or(0);
+            if matches!(self.per_account_limit, Some(limit) if minted >= limit) {
+                return false;
+            }
+            if self.cooldown > 0 {
+                let now = self.env().block_timestamp();
+                let last = self.last_mint_at.get(who);
+                if matches!(last, Some(last) if now.saturating_sub(last) < self.cooldown) {
+                    return false;
+                }
+            }
+            !self.allowlist_only || self.allowlist.contains(who)
+        }
+
+        /// (Optional) Retrieve a minted NFT by its index.
+        #[ink(message)]
+        pub fn get_nft(&self, index: u64) -> Option<Nft> {
+            self.nfts.get(index)
+        }
+
+        /// **Get NFTs**
+        ///
+        /// Returns the NFT at each of `indices`, in input order, with `None` for NFTs that
+        /// don't exist.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` indices are given.
+        #[ink(message)]
+        pub fn get_nfts(&self, indices: Vec<u64>) -> Result<Vec<Option<Nft>>> {
+            if indices.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            Ok(indices.into_iter().map(|index| self.nfts.get(index)).collect())
+        }
+
+        /// **Set Base URI**
+        ///
+        /// Sets the base URI used to derive the metadata URI of NFTs minted without one.
+        /// This function can only be called by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        /// - Returns `Error::MetadataFrozen` if metadata has been frozen.
+        #[ink(message)]
+        pub fn set_base_uri(&mut self, uri: String) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            self.ensure_metadata_mutable()?;
+            self.base_uri.set(&uri);
+            Ok(())
+        }
+
+        /// **Set Contract URI**
+        ///
+        /// Sets the URI of the collection-level metadata, such as banner, description and fees.
+        /// This function can only be called by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        /// - Returns `Error::MetadataFrozen` if metadata has been frozen.
+        #[ink(message)]
+        pub fn set_contract_uri(&mut self, uri: String) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            self.ensure_metadata_mutable()?;
+            self.contract_uri.set(&uri);
+            Ok(())
+        }
+
+        /// **Contract URI**
+        ///
+        /// Returns the URI of the collection-level metadata (empty when none was set).
+        #[ink(message)]
+        pub fn contract_uri(&self) -> String {
+            self.contract_uri.get().unwrap_or_default()
+        }
+
+        /// **Set Placeholder URI**
+        ///
+        /// Sets the URI returned by `token_uri` for every NFT until the collection is revealed.
+        /// An empty placeholder disables the pre-reveal phase.
+        /// This function can only be called by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        /// - Returns `Error::MetadataFrozen` if metadata has been frozen.
+        #[ink(message)]
+        pub fn set_placeholder_uri(&mut self, uri: String) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            self.ensure_metadata_mutable()?;
+            self.placeholder_uri.set(&uri);
+            Ok(())
+        }
+
+        /// **Freeze Metadata**
+        ///
+        /// Permanently freezes the collection metadata so URIs can no longer be changed.
+        /// Freezing is irreversible and can only be done by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        #[ink(message)]
+        pub fn freeze_metadata(&mut self) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            self.metadata_frozen = true;
+            Ok(())
+        }
+
+        /// **Freeze Token**
+        ///
+        /// Permanently freezes the metadata of the NFT at `index`, leaving other NFTs editable.
+        /// Freezing is irreversible and can only be done by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn freeze_token(&mut self, index: u64) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            if !self.nfts.contains(index) {
+                return Err(Error::NFTNotFound);
+            }
+            self.frozen_tokens.insert(index, &());
+            Ok(())
+        }
+
+        /// **Is Token Frozen**
+        ///
+        /// Returns whether the metadata of the NFT at `index` has been frozen individually.
+        #[ink(message)]
+        pub fn is_token_frozen(&self, index: u64) -> bool {
+            self.frozen_tokens.contains(index)
+        }
+
+        /// **Reveal**
+        ///
+        /// Reveals the real per-NFT metadata URIs, replacing the placeholder for good.
+        /// Revealing is one-way and can only be done by the admin or a `ROLE_METADATA`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither the admin nor a `ROLE_METADATA`.
+        #[ink(message)]
+        pub fn reveal(&mut self) -> Result<()> {
+            self.ensure_role(ROLE_METADATA)?;
+            self.revealed = true;
+            Ok(())
+        }
+
+        /// **Token URI**
+        ///
+        /// Returns the metadata URI of the NFT at `index`. Until the collection is revealed, a
+        /// configured placeholder URI is returned for every NFT. Otherwise a URI stored at mint
+        /// time takes precedence, and the URI is derived as `<base_uri><index>.json`, or left
+        /// empty when no base URI is set.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn token_uri(&self, index: u64) -> Result<String> {
+            let nft = self.nfts.get(index).ok_or(Error::NFTNotFound)?;
+            if !self.revealed {
+                let placeholder = self.placeholder_uri.get().unwrap_or_default();
+                if !placeholder.is_empty() {
+                    return Ok(placeholder);
+                }
+            }
+            if !nft.metadata_uri.is_empty() {
+                return Ok(nft.metadata_uri);
+            }
+            let mut uri = self.base_uri.get().unwrap_or_default();
+            if uri.is_empty() {
+                return Ok(nft.metadata_uri);
+            }
+            push_u64(&mut uri, index);
+            uri.push_str(".json");
+            Ok(uri)
+        }
+
+        /// **Royalty Info**
+        ///
+        /// Returns the royalty recipient and the royalty amount owed on a sale of the NFT at
+        /// `index` for `sale_price`, following the EIP-2981 shape. A royalty set for the NFT
+        /// with `set_token_royalty` takes precedence over the collection default.
+        #[ink(message)]
+        pub fn royalty_info(&self, index: u64, sale_price: Balance) -> (AccountId, Balance) {
+            let (recipient, bps) = self
+                .token_royalties
+                .get(index)
+                .unwrap_or((self.royalty_recipient, self.royalty_bps));
+            let bps = Balance::from(bps);
+            let denominator = Balance::from(BPS_DENOMINATOR);
+            // Split the price to compute `sale_price * bps / 10000` without overflowing.
+            let royalty =
+                sale_price / denominator * bps + sale_price % denominator * bps / denominator;
+            (recipient, royalty)
+        }
+
+        /// **Set Token Royalty**
+        ///
+        /// Overrides the collection royalty for the NFT at `index` with `bps` basis points
+        /// payable to `recipient`. This function can only be called by the admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not the admin.
+        /// - Returns `Error::InvalidRoyalty` if `bps` exceeds 10000.
+        /// - Returns `Error::RoyaltyTooHigh` if `bps` exceeds the collection's maximum rate.
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn set_token_royalty(
+            &mut self,
+            index: u64,
+            recipient: AccountId,
+            bps: u16,
+        ) -> Result<()> {
+            self.ensure_admin()?;
+            if bps > BPS_DENOMINATOR {
+                return Err(Error::InvalidRoyalty);
+            }
+            if bps > self.max_royalty_bps {
+                return Err(Error::RoyaltyTooHigh);
+            }
+            if !self.nfts.contains(index) {
+                return Err(Error::NFTNotFound);
+            }
+            self.token_royalties.insert(index, &(recipient, bps));
+            Ok(())
+        }
+
+        /// **Name**
+        ///
+        /// Returns the collection name.
+        #[ink(message)]
+        pub fn name(&self) -> String {
+            self.collection_name.get().unwrap_or_default()
+        }
+
+        /// **Symbol**
+        ///
+        /// Returns the collection symbol.
+        #[ink(message)]
+        pub fn symbol(&self) -> String {
+            self.collection_symbol.get().unwrap_or_default()
+        }
+
+        /// **Supports Interface**
+        ///
+        /// Returns whether the contract implements the interface identified by `id`, allowing
+        /// clients to detect capabilities without trial calls. The supported IDs are
+        /// `INTERFACE_NFT`, `INTERFACE_METADATA`, `INTERFACE_ENUMERABLE` and `INTERFACE_ROYALTY`,
+        /// each the XOR of the selectors of the messages in that interface.
+        #[ink(message)]
+        pub fn supports_interface(&self, id: [u8; 4]) -> bool {
+            SUPPORTED_INTERFACES.contains(&id)
+        }
+
+        /// **Total Supply**
+        ///
+        /// Returns the number of NFTs currently in existence. Unlike the oracle counter,
+        /// this decreases when an NFT is burned.
+        #[ink(message)]
+        pub fn total_supply(&self) -> u64 {
+            self.total_supply
+        }
+
+        /// **Start Index**
+        ///
+        /// Returns the index given to the first NFT minted by the counter.
+        #[ink(message)]
+        pub fn start_index(&self) -> u64 {
+            self.start_index
+        }
+
+        /// **Holder Count**
+        ///
+        /// Returns the number of distinct accounts currently holding at least one NFT.
+        #[ink(message)]
+        pub fn holder_count(&self) -> u64 {
+            self.distinct_owners
+        }
+
+        /// **Remaining Supply**
+        ///
+        /// Returns how many more NFTs can be minted before the maximum supply is reached,
+        /// including any reserved NFTs not minted yet, or `None` if the supply is unlimited.
+        #[ink(message)]
+        pub fn remaining_supply(&self) -> Option<u64> {
+            let cap = self.max_supply?;
+            // Public numbering never starts below the end of the reserved range.
+            let public_index = self.oracle_index.max(self.reserved);
+            let remaining = cap
+                .saturating_sub(public_index)
+                .saturating_add(self.reserved_remaining());
+            Some(remaining.min(cap.saturating_sub(self.minted_total())))
+        }
+
+        /// **Progress Bps**
+        ///
+        /// Returns how much of the maximum supply has been minted, in basis points (10000 meaning
+        /// sold out), or `None` if the supply is unlimited. The minted share is whatever
+        /// `remaining_supply` no longer reports, so unminted reserved NFTs count as open supply.
+        /// A cap of zero counts as sold out.
+        #[ink(message)]
+        pub fn progress_bps(&self) -> Option<u16> {
+            let cap = self.max_supply?;
+            if cap == 0 {
+                return Some(BPS_DENOMINATOR);
+            }
+            let minted = cap.saturating_sub(self.remaining_supply()?);
+            let progress = u128::from(minted) * u128::from(BPS_DENOMINATOR) / u128::from(cap);
+            Some(progress as u16)
+        }
+
+        /// **Exists**
+        ///
+        /// Returns whether an NFT currently exists at `index`, without decoding the record.
+        #[ink(message)]
+        pub fn exists(&self, index: u64) -> bool {
+            self.nfts.contains(index)
+        }
+
+        /// **Minted At**
+        ///
+        /// Returns the block timestamp at which the NFT at `index` was minted.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn minted_at(&self, index: u64) -> Result<Timestamp> {
+            self.nfts
+                .get(index)
+                .map(|nft| nft.minted_at)
+                .ok_or(Error::NFTNotFound)
+        }
+
+        /// **Transfer Count**
+        ///
+        /// Returns how many times the NFT at `index` has changed hands since it was minted.
+        /// Transfers to the current owner are not counted.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn transfer_count(&self, index: u64) -> Result<u32> {
+            self.nfts
+                .get(index)
+                .map(|nft| nft.transfer_count)
+                .ok_or(Error::NFTNotFound)
+        }
+
+        /// **Tokens Of Owner**
+        ///
+        /// Returns the indices of every NFT currently owned by `owner`. The order is not
+        /// guaranteed to follow minting order once NFTs have been transferred or burned.
+        #[ink(message)]
+        pub fn tokens_of_owner(&self, owner: AccountId) -> Vec<u64> {
+            (0..self.balance_of(owner))
+                .filter_map(|position| self.owned_tokens.get((owner, position)))
+                .collect()
+        }
+
+        /// **Owner Of**
+        ///
+        /// Returns the owner of the NFT at `index`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NFTNotFound` if the NFT doesn't exist.
+        #[ink(message)]
+        pub fn owner_of(&self, index: u64) -> Result<AccountId> {
+            self.nfts.get(index).map(|nft| nft.owner).ok_or(Error::NFTNotFound)
+        }
+
+        /// **Owners Of**
+        ///
+        /// Returns the owner of each NFT in `indices`, in input order, with `None` for NFTs
+        /// that don't exist.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if more than `MAX_LOOKUP_SIZE` indices are given.
+        #[ink(message)]
+        pub fn owners_of(&self, indices: Vec<u64>) -> Result<Vec<Option<AccountId>>> {
+            if indices.len() > MAX_LOOKUP_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            Ok(indices
+                .into_iter()
+                .map(|index| self.nfts.get(index).map(|nft| nft.owner))
+                .collect())
+        }
+
+        /// **Tokens Paginated**
+        ///
+        /// Returns up to `limit` existing NFTs, in index order, starting at index `start`,
+        /// along with the index to start the next page at, or `None` once the last minted index
+        /// has been scanned. Burned and reserved but unminted indices are skipped. At most
+        /// `MAX_PAGE_SIZE` indices are scanned per call, so a page may come back short or even
+        /// empty while the cursor still points further; `limit` is capped at `MAX_PAGE_SIZE` too.
+        /// The cursor is returned because the NFTs alone can't tell a caller where to go on:
+        /// after a run of burned indices a page may be empty, with no last index to page from,
+        /// and an empty page would otherwise be mistaken for the end of the collection.
+        #[ink(message)]
+        pub fn tokens_paginated(&self, start: u64, limit: u64) -> (Vec<(u64, Nft)>, Option<u64>) {
+            let limit = limit.min(MAX_PAGE_SIZE) as usize;
+            let last = self.token_index(self.oracle_index).unwrap_or(u64::MAX);
+            let scan_end = start.saturating_add(MAX_PAGE_SIZE);
+            let mut page = Vec::new();
+            let mut index = start;
+            while index <= last && index < scan_end && page.len() < limit {
+                if let Some(nft) = self.nfts.get(index) {
+                    page.push((index, nft));
+                }
+                let Some(next) = index.checked_add(1) else {
+                    return (page, None);
+                };
+                index = next;
+            }
+            (page, (index <= last).then_some(index))
+        }
+
+        /// **Range**
+        ///
+        /// Returns the existing NFTs with an index in `[from, to)`, in index order. Burned and
+        /// not yet minted indices are skipped. An empty range is returned when `to <= from`.
+        ///
+        /// # Errors
+        /// - Returns `Error::TooManyItems` if the range spans more than `MAX_PAGE_SIZE` indices.
+        #[ink(message)]
+        pub fn range(&self, from: u64, to: u64) -> Result<Vec<(u64, Nft)>> {
+            if to.saturating_sub(from) > MAX_PAGE_SIZE {
+                return Err(Error::TooManyItems);
+            }
+            Ok((from..to)
+                .filter_map(|index| self.nfts.get(index).map(|nft| (index, nft)))
+                .collect())
+        }
+
+        /// **Balance Of**
+        ///
+        /// Returns the number of NFTs currently owned by `owner`.
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> u64 {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        /// **Mints By**
+        ///
+        /// Returns the cumulative number of NFTs `who` has minted for itself, regardless of
+        /// how many it still owns.
+        #[ink(message)]
+        pub fn mints_by(&self, who: AccountId) -> u32 {
+            self.minted_by.get(who).unwrap_or(0)
+        }
+    }
+
+    //----------------------------------
+    // Internal Helpers
+    //----------------------------------
+
+    #[ink(impl)]
+    impl NFTMintingMachine {
+        /// Checks that the caller is the admin.
+        fn ensure_admin(&self) -> Result<()> {
+            if self.env().caller() != self.admin {
+                return Err(Error::NotAdmin);
+            }
+            Ok(())
+        }
+
+        /// Checks that the collection metadata has not been frozen.
+        fn ensure_metadata_mutable(&self) -> Result<()> {
+            if self.metadata_frozen {
+                return Err(Error::MetadataFrozen);
+            }
+            Ok(())
+        }
+
+        /// Checks that neither the collection metadata nor the NFT at `index` has been frozen.
+        fn ensure_token_mutable(&self, index: u64) -> Result<()> {
+            self.ensure_metadata_mutable()?;
+            if self.frozen_tokens.contains(index) {
+                return Err(Error::MetadataFrozen);
+            }
+            Ok(())
+        }
+
+        /// Checks that the caller is the admin or holds `role`.
+        fn ensure_role(&self, role: u8) -> Result<()> {
+            if !self.has_role(self.env().caller(), role) {
+                return Err(Error::NotAdmin);
+            }
+            Ok(())
+        }
+
+        /// Checks that the NFT at `index` is not locked.
+        fn ensure_unlocked(&self, index: u64) -> Result<()> {
+            if self.locked.contains(index) {
+                return Err(Error::TokenLocked);
+            }
+            Ok(())
+        }
+
+        /// Checks that the caller owns the NFT at `index` and returns it.
+        fn ensure_owner(&self, index: u64) -> Result<Nft> {
+            let nft = self.nfts.get(index).ok_or(Error::NFTNotFound)?;
+            if self.env().caller() != nft.owner {
+                return Err(Error::NotOwner);
+            }
+            Ok(nft)
+        }
+
+        /// Checks that NFTs of this collection are allowed to change hands.
+        fn ensure_transferable(&self) -> Result<()> {
+            if self.soulbound {
+                return Err(Error::NonTransferable);
+            }
+            Ok(())
+        }
+
+        /// Mints the next NFT to `owner` after checking that minting is enabled.
+        fn mint_for(&mut self, owner: AccountId, metadata_uri: String) -> Result<u64> {
+            // Increment the NFT counter
+            let counter = self.ensure_can_mint(1)?;
+            let next_index = self.token_index(counter)?;
+            self.oracle_index = counter;
+            Ok(self.create_token(next_index, owner, metadata_uri))
+        }
+
+        /// Stores a new NFT at `index` owned by `owner` and emits a `Minted` event.
+        fn create_token(&mut self, index: u64, owner: AccountId, metadata_uri: String) -> u64 {
+            // Generate the NFT token name based on the new index
+            let mut token_name = self.prefix.clone();
+            push_u64_padded(&mut token_name, index, self.pad_width);
+
+            let nft = Nft {
+                token_name,
+                owner,
+                metadata_uri,
+                minted_at: self.env().block_timestamp(),
+                transfer_count: 0,
+            };
+            self.insert_token(index, nft)
+        }
+
+        /// Stores `nft` at `index`, emits a `Minted` event and notifies the observer, if any.
+        fn insert_token(&mut self, index: u64, nft: Nft) -> u64 {
+            self.nfts.insert(index, &nft);
+            self.total_supply = self.total_supply.saturating_add(1);
+            self.minted_total.set(&self.minted_total().saturating_add(1));
+            self.add_owned_token(nft.owner, index);
+            self.env().emit_event(Minted {
+                index,
+                owner: nft.owner,
+                token_name: nft.token_name,
+            });
+            self.last_minted_index = index;
+            self.last_minter = Some(self.env().caller());
+            self.notify_observer(index, nft.owner);
+            index
+        }
+
+        /// Calls `on_mint(index, owner)` on the observer, if one is set. The call is best-effort:
+        /// its outcome is ignored, so a failing observer never reverts the mint.
+        /// The observer cannot call back into this contract, as re-entry is not allowed.
+        fn notify_observer(&self, index: u64, owner: AccountId) {
+            let Some(observer) = self.observer else {
+                return;
+            };
+            let _ = build_call::<Environment>()
+                .call(observer)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ON_MINT))
+                        .push_arg(index)
+                        .push_arg(owner),
+                )
+                .returns::<()>()
+                .try_invoke();
+        }
+
+        /// Destroys the NFT at `index` held by `owner` and emits a `Burned` event followed by a
+        /// `Transfer` event to the zero account.
+        fn burn_token(&mut self, index: u64, owner: AccountId) {
+            self.nfts.remove(index);
+            self.approvals.remove(index);
+            self.token_royalties.remove(index);
+            self.frozen_tokens.remove(index);
+            for key in self.attribute_keys.take(index).unwrap_or_default() {
+                self.attributes.remove((index, key));
+            }
+            self.total_supply = self.total_supply.saturating_sub(1);
+            self.remove_owned_token(owner, index);
+            self.env().emit_event(Burned { index, owner });
+            self.env().emit_event(Transfer {
+                from: owner,
+                to: AccountId::from([0u8; 32]),
+                index,
+            });
+        }
+
+        /// Moves `nft` to `new_owner`, clearing its approval and emitting a `Transfer` event.
+        fn move_token(&mut self, index: u64, mut nft: Nft, new_owner: AccountId) {
+            let previous_owner = nft.owner;
+            nft.owner = new_owner;
+            if previous_owner != new_owner {
+                nft.transfer_count = nft.transfer_count.saturating_add(1);
+            }
+            self.nfts.insert(index, &nft);
+            self.approvals.remove(index);
+            if previous_owner != new_owner {
+                self.remove_owned_token(previous_owner, index);
+                self.add_owned_token(new_owner, index);
+            }
+            self.env().emit_event(Transfer {
+                from: previous_owner,
+                to: new_owner,
+                index,
+            });
+        }
+
+        /// Checks that `count` more NFTs can be minted and returns the counter the last one would
+        /// get. Use `token_index` to turn the counter into an NFT index.
+        fn ensure_can_mint(&self, count: u64) -> Result<u64> {
+            if !self.oracle_setup {
+                return Err(Error::OracleNotSetup);
+            }
+            if self.paused {
+                return Err(Error::Paused);
+            }
+            // Check the cap first, so a sold-out collection reports it even at the integer limit.
+            // After `reset_oracle` the counter no longer tells how many NFTs were minted, so the
+            // lifetime total, with the unminted reserve, is checked as well.
+            let available = self.max_supply.map(|cap| {
+                let lifetime = cap
+                    .saturating_sub(self.minted_total())
+                    .saturating_sub(self.reserved_remaining());
+                cap.saturating_sub(self.oracle_index).min(lifetime)
+            });
+            if matches!(available, Some(available) if count > available) {
+                return Err(Error::MaxSupplyReached);
+            }
+            let last_index = self.oracle_index.checked_add(count).ok_or(Error::CounterOverflow)?;
+            self.token_index(last_index)?;
+            Ok(last_index)
+        }
+
+        /// Returns the number of NFTs ever minted, burned ones included.
+        fn minted_total(&self) -> u64 {
+            self.minted_total.get().unwrap_or(0)
+        }
+
+        /// Returns the index of the NFT minted at position `counter` of the counter, which starts
+        /// at 1, shifted so that the first NFT gets `start_index`.
+        fn token_index(&self, counter: u64) -> Result<u64> {
+            counter
+                .saturating_sub(1)
+                .checked_add(self.start_index)
+                .ok_or(Error::CounterOverflow)
+        }
+
+        /// Computes the Merkle root reached by walking `proof` up from the leaf of `account`.
+        fn merkle_root_of(&self, account: AccountId, proof: &[[u8; 32]]) -> [u8; 32] {
+            let leaf = self.env().hash_bytes::<Keccak256>(account.as_ref());
+            proof.iter().fold(leaf, |node, sibling| {
+                let (first, second) = if node <= *sibling {
+                    (node, *sibling)
+                } else {
+                    (*sibling, node)
+                };
+                let mut pair = [0u8; 64];
+                pair[..32].copy_from_slice(&first);
+                pair[32..].copy_from_slice(&second);
+                self.env().hash_bytes::<Keccak256>(&pair)
+            })
+        }
+
+        /// Checks that the caller is on the allowlist when minting is restricted to it.
+        fn ensure_allowlisted(&self) -> Result<()> {
+            if self.allowlist_only && !self.allowlist.contains(self.env().caller()) {
+                return Err(Error::NotAllowlisted);
+            }
+            Ok(())
+        }
+
+        /// Runs the payable mint of `count` NFTs for the caller shared by every public mint
+        /// message: it runs `prepare_public_mint`, then `body` with the caller to mint the NFTs,
+        /// and finally refunds whatever was transferred beyond the price of the NFTs, fees
+        /// included.
+        fn public_mint<T>(
+            &mut self,
+            count: u32,
+            body: impl FnOnce(&mut Self, AccountId) -> Result<T>,
+        ) -> Result<T> {
+            let price = self.price_of(u64::from(count));
+            let caller = self.prepare_public_mint(count)?;
+            let minted = body(self, caller)?;
+            let excess = self.env().transferred_value().saturating_sub(price);
+            if excess > 0 {
+                self.env()
+                    .transfer(caller, excess)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+            Ok(minted)
+        }
+
+        /// Runs every check that applies when the caller mints `count` NFTs for itself, records
+        /// the mints against its per-account limit and forwards the platform fee to the
+        /// treasury. Returns the caller.
+        fn prepare_public_mint(&mut self, count: u32) -> Result<AccountId> {
+            self.ensure_can_mint(u64::from(count))?;
+            self.ensure_mint_window()?;
+            self.ensure_paid(u64::from(count))?;
+            let caller = self.env().caller();
+            let minted = self.minted_by.get(caller).unwrap_or(0).saturating_add(count);
+            if matches!(self.per_account_limit, Some(limit) if minted > limit) {
+                return Err(Error::MintLimitReached);
+            }
+            if self.cooldown > 0 {
+                let now = self.env().block_timestamp();
+                let last = self.last_mint_at.get(caller);
+                if matches!(last, Some(last) if now.saturating_sub(last) < self.cooldown) {
+                    return Err(Error::CooldownActive);
+                }
+                self.last_mint_at.insert(caller, &now);
+            }
+            self.minted_by.insert(caller, &minted);
+            if self.platform_fee > 0 {
+                let fee = self.platform_fee.saturating_mul(Balance::from(count));
+                self.env()
+                    .transfer(self.treasury, fee)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+            Ok(caller)
+        }
+
+        /// Checks that the current block timestamp and number fall within the mint windows.
+        fn ensure_mint_window(&self) -> Result<()> {
+            let now = self.env().block_timestamp();
+            if matches!(self.mint_start, Some(start) if now < start)
+                || matches!(self.mint_end, Some(end) if now >= end)
+            {
+                return Err(Error::MintNotActive);
+            }
+            let block = self.env().block_number();
+            if matches!(self.mint_start_block, Some(start) if block < start)
+                || matches!(self.mint_end_block, Some(end) if block >= end)
+            {
+                return Err(Error::MintNotActive);
+            }
+            Ok(())
+        }
+
+        /// Returns the mint price, without the platform fee, of the NFT minted once the counter
+        /// has reached `counter`.
+        fn price_at(&self, counter: u64) -> Balance {
+            self.price_tiers
+                .iter()
+                .rev()
+                .find(|(threshold, _)| *threshold <= counter)
+                .map_or(self.price, |(_, price)| *price)
+        }
+
+        /// Returns the price of the next `count` NFTs including fees, pricing each NFT at the
+        /// tier the counter has reached when it is minted.
+        fn price_of(&self, count: u64) -> Balance {
+            (0..count).fold(0, |total: Balance, offset| {
+                total
+                    .saturating_add(self.price_at(self.oracle_index.saturating_add(offset)))
+                    .saturating_add(self.platform_fee)
+            })
+        }
+
+        /// Checks that the transferred value covers the price of `count` NFTs, including fees.
+        fn ensure_paid(&self, count: u64) -> Result<()> {
+            let required = self.price_of(count);
+            if self.env().transferred_value() < required {
+                return Err(Error::InsufficientPayment);
+            }
+            Ok(())
+        }
+
+        /// Appends `index` to the NFTs held by `owner` and increases its count by one.
+        fn add_owned_token(&mut self, owner: AccountId, index: u64) {
+            let balance = self.balance_of(owner);
+            if balance == 0 {
+                self.distinct_owners = self.distinct_owners.saturating_add(1);
+            }
+            self.owned_tokens.insert((owner, balance), &index);
+            self.owned_token_positions.insert(index, &balance);
+            self.balances.insert(owner, &balance.saturating_add(1));
+        }
+
+        /// Removes `index` from the NFTs held by `owner` and decreases its count by one.
+        /// The last NFT in the owner's list takes the place of the removed one.
+        fn remove_owned_token(&mut self, owner: AccountId, index: u64) {
+            let last = self.balance_of(owner).saturating_sub(1);
+            let position = self.owned_token_positions.take(index).unwrap_or(last);
+            if let Some(last_index) = self.owned_tokens.take((owner, last)) {
+                if position != last {
+                    self.owned_tokens.insert((owner, position), &last_index);
+                    self.owned_token_positions.insert(last_index, &position);
+                }
+            }
+            if last == 0 {
+                self.balances.remove(owner);
+                self.distinct_owners = self.distinct_owners.saturating_sub(1);
+            } else {
+                self.balances.insert(owner, &last);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::{test, DefaultEnvironment};
+
+        /// Tests the `push_u64` function used to build token names.
+        /// - Verifies that the output matches `to_string` for edge and ordinary values.
+        /// - Verifies that the digits are appended to the existing buffer contents.
+        /// - Verifies that `push_u64_padded` pads short numbers and keeps long ones whole.
+        #[test]
+        fn test_push_u64() {
+            for n in [0, 1, 9, 10, 99, 100, 1_234_567_890, u64::MAX - 1, u64::MAX] {
+                let mut buf = String::new();
+                push_u64(&mut buf, n);
+                assert_eq!(buf, n.to_string());
+            }
+
+            let mut buf = String::from("NFT #");
+            push_u64(&mut buf, 42);
+            assert_eq!(buf, "NFT #42");
+
+            for (n, width, expected) in [(1, 4, "0001"), (0, 3, "000"), (12_345, 4, "12345")] {
+                let mut buf = String::new();
+                push_u64_padded(&mut buf, n, width);
+                assert_eq!(buf, expected);
+            }
+        }
+
+        /// Tests the `interface_id` function to ensure selectors are combined with XOR.
+        /// - Verifies that a single selector is its own ID and that order doesn't matter.
+        #[test]
+        fn test_interface_id() {
+            assert_eq!(interface_id(&[]), [0; 4]);
+            assert_eq!(interface_id(&[[1, 2, 3, 4]]), [1, 2, 3, 4]);
+            assert_eq!(interface_id(&[[1, 2, 3, 4], [0xff, 0, 0x0f, 4]]), [0xfe, 2, 0x0c, 0]);
+            assert_eq!(
+                interface_id(&[[0xff, 0, 0x0f, 4], [1, 2, 3, 4]]),
+                interface_id(&[[1, 2, 3, 4], [0xff, 0, 0x0f, 4]])
+            );
+        }
+
+        /// Tests that `progress_bps` agrees with `remaining_supply` on a capped collection with a
+        /// reserve.
+        /// - Verifies that the unminted reserve is not reported as progress.
+        /// - Verifies that reserved and public mints both advance progress.
+        #[ink::test]
+        fn test_progress_bps_with_reserve() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let mut contract = NFTMintingMachine {
+                reserved: 2,
+                ..NFTMintingMachine::new_with_cap(4)
+            };
+            contract.setup_oracle().unwrap();
+            assert_eq!(contract.remaining_supply(), Some(4));
+            assert_eq!(contract.progress_bps(), Some(0));
+
+            contract.mint_reserved(accounts.bob, 1).unwrap();
+            assert_eq!(contract.progress_bps(), Some(2_500));
+            contract.mint_token().unwrap();
+            assert_eq!(contract.remaining_supply(), Some(2));
+            assert_eq!(contract.progress_bps(), Some(5_000));
+        }
+
+        /// Tests the `rarity_tier` function to ensure rolls map onto the weighted tiers.
+        /// - Verifies the boundaries between tiers and that rolls wrap at `BPS_DENOMINATOR`.
+        #[test]
+        fn test_rarity_tier() {
+            let seed = |roll: u16| {
+                let mut seed = [0u8; 32];
+                seed[..2].copy_from_slice(&roll.to_le_bytes());
+                seed
+            };
+            assert_eq!(rarity_tier(seed(0)), 0);
+            assert_eq!(rarity_tier(seed(RARITY_WEIGHTS[0] - 1)), 0);
+            assert_eq!(rarity_tier(seed(RARITY_WEIGHTS[0])), 1);
+            assert_eq!(rarity_tier(seed(9_499)), 2);
+            assert_eq!(rarity_tier(seed(9_999)), 3);
+            assert_eq!(rarity_tier(seed(10_000)), 0);
+        }
+
+        /// Tests that `mint_with_seed` draws the tier from the block timestamp, the caller and
+        /// the index.
+        #[ink::test]
+        fn test_mint_with_seed_derivation() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_block_timestamp::<DefaultEnvironment>(1_234);
+            let mut contract = NFTMintingMachine::new();
+            contract.setup_oracle().unwrap();
+            for _ in 0..3 {
+                let index = contract.mint_with_seed().unwrap();
+                let mut seed = [0u8; 32];
+                ink::env::hash_encoded::<Blake2x256, _>(
+                    &(1_234u64, accounts.alice, index),
+                    &mut seed,
+                );
+                assert_eq!(contract.rarity(index), Some(rarity_tier(seed)));
+            }
         }
     }
 }
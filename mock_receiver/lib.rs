@@ -0,0 +1,138 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::nft_receiver_mock::{NftReceiverMock, NftReceiverMockRef};
+
+/// The `nft_receiver_mock` module defines a minimal contract used by the end-to-end tests of the
+/// `NFTMintingMachine` contract to stand in for a contract recipient of `safe_transfer` and
+/// for a mint observer. It can also act maliciously by re-entering `safe_transfer` or
+/// `mint_with_uri`.
+#[ink::contract]
+mod nft_receiver_mock {
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::prelude::{string::String, vec::Vec};
+
+    /// The `NftReceiverMock` contract records the NFTs it is notified about and either accepts
+    /// or rejects them depending on how it was constructed.
+    #[ink(storage)]
+    pub struct NftReceiverMock {
+        /// Flag indicating whether `on_nft_received` and `on_mint` accept their notifications.
+        accept: bool,
+        /// The index of the last NFT this contract was notified about.
+        last_received: Option<u64>,
+        /// The index and owner of the last mint this contract was notified about.
+        last_minted: Option<(u64, AccountId)>,
+        /// The account a received NFT is forwarded to by re-entering `safe_transfer`, if any.
+        reenter_to: Option<AccountId>,
+        /// Flag indicating whether a received NFT triggers a re-entrant `mint_with_uri`.
+        reenter_mint: bool,
+        /// The result of the last re-entrant call, with the error as its index.
+        reentry_result: Option<Result<(), u8>>,
+    }
+
+    impl NftReceiverMock {
+        /// Constructor: Initializes the mock so that it accepts (`true`) or rejects (`false`)
+        /// every incoming NFT.
+        #[ink(constructor)]
+        pub fn new(accept: bool) -> Self {
+            Self {
+                accept,
+                last_received: None,
+                last_minted: None,
+                reenter_to: None,
+                reenter_mint: false,
+                reentry_result: None,
+            }
+        }
+
+        /// Called by `safe_transfer` when an NFT is sent to this contract. Returns the
+        /// `on_nft_received` selector to accept the NFT, or zeroes to reject it.
+        #[ink(message)]
+        pub fn on_nft_received(
+            &mut self,
+            _operator: AccountId,
+            _from: AccountId,
+            index: u64,
+            _data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.last_received = Some(index);
+            if let Some(to) = self.reenter_to {
+                let result = build_call::<Environment>()
+                    .call(self.env().caller())
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink::selector_bytes!("safe_transfer")))
+                            .push_arg(to)
+                            .push_arg(index)
+                            .push_arg(Vec::<u8>::new()),
+                    )
+                    .returns::<Result<(), u8>>()
+                    .try_invoke();
+                self.reentry_result = Some(match result {
+                    Ok(Ok(result)) => result,
+                    _ => Err(u8::MAX),
+                });
+            }
+            if self.reenter_mint {
+                let result = build_call::<Environment>()
+                    .call(self.env().caller())
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink::selector_bytes!("mint_with_uri")))
+                            .push_arg(String::new()),
+                    )
+                    .returns::<Result<u64, u8>>()
+                    .try_invoke();
+                self.reentry_result = Some(match result {
+                    Ok(Ok(result)) => result.map(|_| ()),
+                    _ => Err(u8::MAX),
+                });
+            }
+            if self.accept {
+                ink::selector_bytes!("on_nft_received")
+            } else {
+                [0u8; 4]
+            }
+        }
+
+        /// Returns the index of the last NFT this contract was notified about.
+        #[ink(message)]
+        pub fn last_received(&self) -> Option<u64> {
+            self.last_received
+        }
+
+        /// Makes `on_nft_received` try to forward each received NFT to `to` by re-entering
+        /// `safe_transfer`, or stops doing so when `to` is `None`.
+        #[ink(message)]
+        pub fn set_reenter(&mut self, to: Option<AccountId>) {
+            self.reenter_to = to;
+        }
+
+        /// Makes `on_nft_received` try to mint an NFT for itself by re-entering `mint_with_uri`,
+        /// a payable mint rather than another transfer.
+        #[ink(message)]
+        pub fn set_reenter_mint(&mut self, enabled: bool) {
+            self.reenter_mint = enabled;
+        }
+
+        /// Returns the result of the last re-entrant call, where an error is given
+        /// as the index of the `Error` variant, or `u8::MAX` if the call itself failed.
+        #[ink(message)]
+        pub fn reentry_result(&self) -> Option<Result<(), u8>> {
+            self.reentry_result
+        }
+
+        /// Called by the `NFTMintingMachine` after each mint when this contract is its observer.
+        /// A rejecting mock traps, so that tests can check the mint goes through regardless.
+        #[ink(message)]
+        pub fn on_mint(&mut self, index: u64, owner: AccountId) {
+            if !self.accept {
+                panic!("mint notification rejected");
+            }
+            self.last_minted = Some((index, owner));
+        }
+
+        /// Returns the index and owner of the last mint this contract was notified about.
+        #[ink(message)]
+        pub fn last_minted(&self) -> Option<(u64, AccountId)> {
+            self.last_minted
+        }
+    }
+}
@@ -0,0 +1,345 @@
+/// # NFTMintingMachine End-to-End Test Suite
+///
+/// This module contains end-to-end tests for the behaviour of the `NFTMintingMachine` contract
+/// that depends on cross-contract calls, which the off-chain test environment cannot execute.
+/// They run against a node with `pallet-contracts`, e.g. `substrate-contracts-node`:
+///
+/// ```sh
+/// cargo test --features e2e-tests
+/// ```
+///
+/// The `NftReceiverMock` contract from `mock_receiver/` stands in for a contract recipient and
+/// for a mint observer.
+#[cfg(all(test, feature = "e2e-tests"))]
+mod e2e_tests {
+    use ink_e2e::ContractsBackend;
+    use nft_minting_machine::{Error, NFTMintingMachine, NFTMintingMachineRef};
+    use nft_receiver_mock::{NftReceiverMock, NftReceiverMockRef};
+
+    type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Tests the `safe_transfer` function with a contract recipient.
+    /// - Verifies that a recipient returning the magic value receives the NFT and is notified.
+    /// - Verifies that a recipient returning anything else makes the transfer fail with
+    ///   `Error::TransferRejected`, leaving the NFT with its owner.
+    #[ink_e2e::test]
+    async fn e2e_safe_transfer_to_contract<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = NFTMintingMachineRef::new();
+        let nft = client
+            .instantiate("nft_minting_machine", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut nft_call = nft.call_builder::<NFTMintingMachine>();
+
+        let mut constructor = NftReceiverMockRef::new(true);
+        let accepting = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let accepting_call = accepting.call_builder::<NftReceiverMock>();
+
+        let mut constructor = NftReceiverMockRef::new(false);
+        let rejecting = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.setup_oracle())
+            .submit()
+            .await
+            .expect("setup_oracle failed");
+        let token_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+
+        let rejected = client
+            .call(
+                &ink_e2e::alice(),
+                &nft_call.safe_transfer(rejecting.account_id, token_index, Vec::new()),
+            )
+            .dry_run()
+            .await?;
+        assert_eq!(rejected.return_value(), Err(Error::TransferRejected));
+
+        client
+            .call(
+                &ink_e2e::alice(),
+                &nft_call.safe_transfer(accepting.account_id, token_index, vec![1, 2, 3]),
+            )
+            .submit()
+            .await
+            .expect("safe_transfer failed");
+        let owner = client
+            .call(&ink_e2e::alice(), &nft_call.owner_of(token_index))
+            .dry_run()
+            .await?;
+        assert_eq!(owner.return_value(), Ok(accepting.account_id));
+        let received = client
+            .call(&ink_e2e::alice(), &accepting_call.last_received())
+            .dry_run()
+            .await?;
+        assert_eq!(received.return_value(), Some(token_index));
+
+        Ok(())
+    }
+
+    /// Tests re-entry into `safe_transfer` from a malicious contract recipient.
+    /// - Verifies that re-entering `safe_transfer` from `on_nft_received` fails, as the hook is
+    ///   called without allowing re-entry.
+    /// - Verifies that the outer transfer completes and the NFT stays with the recipient.
+    #[ink_e2e::test]
+    async fn e2e_safe_transfer_reentrancy<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = NFTMintingMachineRef::new();
+        let nft = client
+            .instantiate("nft_minting_machine", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut nft_call = nft.call_builder::<NFTMintingMachine>();
+
+        let mut constructor = NftReceiverMockRef::new(true);
+        let malicious = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut malicious_call = malicious.call_builder::<NftReceiverMock>();
+        let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+        client
+            .call(&ink_e2e::alice(), &malicious_call.set_reenter(Some(bob)))
+            .submit()
+            .await
+            .expect("set_reenter failed");
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.setup_oracle())
+            .submit()
+            .await
+            .expect("setup_oracle failed");
+        let token_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+
+        client
+            .call(
+                &ink_e2e::alice(),
+                &nft_call.safe_transfer(malicious.account_id, token_index, Vec::new()),
+            )
+            .submit()
+            .await
+            .expect("safe_transfer failed");
+        let reentry = client
+            .call(&ink_e2e::alice(), &malicious_call.reentry_result())
+            .dry_run()
+            .await?;
+        assert_eq!(reentry.return_value(), Some(Err(u8::MAX)));
+        let owner = client
+            .call(&ink_e2e::alice(), &nft_call.owner_of(token_index))
+            .dry_run()
+            .await?;
+        assert_eq!(owner.return_value(), Ok(malicious.account_id));
+
+        Ok(())
+    }
+
+    /// Tests re-entry into `mint_with_uri` from a malicious contract recipient.
+    /// - Verifies that the runtime refuses the re-entrant mint, as the hook is called without
+    ///   the call flags that allow re-entry, so it never runs on stale storage.
+    /// - Verifies that the next mint gets a fresh index and every NFT keeps its owner.
+    #[ink_e2e::test]
+    async fn e2e_mint_reentry_refused<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = NFTMintingMachineRef::new();
+        let nft = client
+            .instantiate("nft_minting_machine", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut nft_call = nft.call_builder::<NFTMintingMachine>();
+
+        let mut constructor = NftReceiverMockRef::new(true);
+        let malicious = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut malicious_call = malicious.call_builder::<NftReceiverMock>();
+        client
+            .call(&ink_e2e::alice(), &malicious_call.set_reenter_mint(true))
+            .submit()
+            .await
+            .expect("set_reenter_mint failed");
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.setup_oracle())
+            .submit()
+            .await
+            .expect("setup_oracle failed");
+        let token_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+
+        client
+            .call(
+                &ink_e2e::alice(),
+                &nft_call.safe_transfer(malicious.account_id, token_index, Vec::new()),
+            )
+            .submit()
+            .await
+            .expect("safe_transfer failed");
+        let reentry = client
+            .call(&ink_e2e::alice(), &malicious_call.reentry_result())
+            .dry_run()
+            .await?;
+        assert_eq!(reentry.return_value(), Some(Err(u8::MAX)));
+
+        let next_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+        assert_eq!(next_index, token_index + 1);
+        let owner = client
+            .call(&ink_e2e::alice(), &nft_call.owner_of(token_index))
+            .dry_run()
+            .await?;
+        assert_eq!(owner.return_value(), Ok(malicious.account_id));
+        let supply = client
+            .call(&ink_e2e::alice(), &nft_call.total_supply())
+            .dry_run()
+            .await?;
+        assert_eq!(supply.return_value(), 2);
+
+        Ok(())
+    }
+
+    /// Tests the mint observer notified through `on_mint`.
+    /// - Verifies that an accepting observer receives the index and owner of each mint.
+    /// - Verifies that a failing observer does not revert the mint.
+    #[ink_e2e::test]
+    async fn e2e_mint_observer<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = NFTMintingMachineRef::new();
+        let nft = client
+            .instantiate("nft_minting_machine", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut nft_call = nft.call_builder::<NFTMintingMachine>();
+
+        let mut constructor = NftReceiverMockRef::new(true);
+        let accepting = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let accepting_call = accepting.call_builder::<NftReceiverMock>();
+
+        let mut constructor = NftReceiverMockRef::new(false);
+        let rejecting = client
+            .instantiate("nft_receiver_mock", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.setup_oracle())
+            .submit()
+            .await
+            .expect("setup_oracle failed");
+        client
+            .call(&ink_e2e::alice(), &nft_call.set_observer(Some(accepting.account_id)))
+            .submit()
+            .await
+            .expect("set_observer failed");
+        let token_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+        let minted = client
+            .call(&ink_e2e::alice(), &accepting_call.last_minted())
+            .dry_run()
+            .await?;
+        let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+        assert_eq!(minted.return_value(), Some((token_index, alice)));
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.set_observer(Some(rejecting.account_id)))
+            .submit()
+            .await
+            .expect("set_observer failed");
+        let token_index = client
+            .call(&ink_e2e::alice(), &nft_call.mint_token())
+            .submit()
+            .await
+            .expect("mint_token failed")
+            .return_value()
+            .expect("mint_token returned an error");
+        let owner = client
+            .call(&ink_e2e::alice(), &nft_call.owner_of(token_index))
+            .dry_run()
+            .await?;
+        assert_eq!(owner.return_value(), Ok(alice));
+
+        Ok(())
+    }
+
+    /// Tests the `set_code` function.
+    /// - Verifies that a non-admin cannot replace the contract code.
+    /// - Verifies that the admin can, after which the contract runs the new code.
+    #[ink_e2e::test]
+    async fn e2e_set_code<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = NFTMintingMachineRef::new();
+        let nft = client
+            .instantiate("nft_minting_machine", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let mut nft_call = nft.call_builder::<NFTMintingMachine>();
+        let code_hash = client
+            .upload("nft_receiver_mock", &ink_e2e::alice())
+            .submit()
+            .await
+            .expect("upload failed")
+            .code_hash;
+
+        let rejected = client
+            .call(&ink_e2e::bob(), &nft_call.set_code(code_hash))
+            .dry_run()
+            .await?;
+        assert_eq!(rejected.return_value(), Err(Error::NotAdmin));
+
+        client
+            .call(&ink_e2e::alice(), &nft_call.set_code(code_hash))
+            .submit()
+            .await
+            .expect("set_code failed");
+        let upgraded_call = ink_e2e::create_call_builder::<NftReceiverMock>(nft.account_id);
+        let received = client
+            .call(&ink_e2e::alice(), &upgraded_call.last_received())
+            .dry_run()
+            .await?;
+        assert_eq!(received.return_value(), None);
+
+        Ok(())
+    }
+}
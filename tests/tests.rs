@@ -9,17 +9,90 @@
 /// - **Charlie**: Unauthorized third party
 #[cfg(test)]
 mod tests {
-    use nft_minting_machine::{NFTMintingMachine, Error};
-    use ink::env::{test, DefaultEnvironment};
+    use nft_minting_machine::{NFTMintingMachine, Error, Minted, Transfer, AdminChanged, OracleSetup};
+    use nft_minting_machine::{
+        INTERFACE_NFT, INTERFACE_METADATA, INTERFACE_ENUMERABLE, INTERFACE_ROYALTY,
+    };
+    use nft_minting_machine::{ROLE_MINTER, ROLE_PAUSER};
+    use nft_minting_machine::{MAX_LOOKUP_SIZE, MAX_NAME_LENGTH, SupplyCheckpoint};
+    use nft_minting_machine::{DEFAULT_MAX_BATCH, Burned, ROLE_LOCKER, AdminForceTransfer};
+    use nft_minting_machine::{MintStatus, Approval, ApprovalForAll, STORAGE_VERSION};
+    use nft_minting_machine::{MAX_PREFIX_LENGTH, MAX_OPERATORS, RARITY_WEIGHTS};
+    use nft_minting_machine::RARITY_ATTRIBUTE;
+    use nft_minting_machine::{MAX_PAGE_SIZE, MAX_ATTRIBUTES};
+    use ink::env::{hash::{Blake2x256, Keccak256}, test, DefaultEnvironment};
+    use ink::primitives::AccountId;
+
+    /// Decodes the most recently emitted event as `E`.
+    fn last_event<E: scale::Decode>() -> E {
+        let event = test::recorded_events().last().expect("no event was emitted");
+        E::decode(&mut &event.data[..]).expect("failed to decode event")
+    }
+
+    /// Hashes `data` with Keccak-256.
+    fn keccak(data: &[u8]) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<Keccak256>(data, &mut output);
+        output
+    }
+
+    /// Hashes two Merkle nodes in ascending order, matching `mint_with_proof`.
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        keccak(&[first, second].concat())
+    }
+
+    /// Signs a `mint_with_signature` authorization for `(contract, recipient, nonce)` with
+    /// `secret`.
+    fn sign_mint(
+        secret: &[u8; 32],
+        contract: AccountId,
+        recipient: AccountId,
+        nonce: u64,
+    ) -> [u8; 65] {
+        let mut hash = [0u8; 32];
+        ink::env::hash_encoded::<Blake2x256, _>(&(contract, recipient, nonce), &mut hash);
+        let secp = secp256k1::Secp256k1::new();
+        let key = secp256k1::SecretKey::from_slice(secret).expect("invalid secret key");
+        let message = secp256k1::Message::from_digest(hash);
+        let (recovery_id, compact) = secp
+            .sign_ecdsa_recoverable(&message, &key)
+            .serialize_compact();
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        signature
+    }
+
+    /// Returns the account derived from the ECDSA public key of `secret`.
+    fn ecdsa_account(secret: &[u8; 32]) -> AccountId {
+        let secp = secp256k1::Secp256k1::new();
+        let key = secp256k1::SecretKey::from_slice(secret).expect("invalid secret key");
+        let public_key = key.public_key(&secp).serialize();
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<Blake2x256>(&public_key, &mut output);
+        AccountId::from(output)
+    }
+
+    /// Returns the Merkle leaf of `account`.
+    fn leaf(account: AccountId) -> [u8; 32] {
+        keccak(account.as_ref())
+    }
 
     /// Tests the `setup_oracle` function to ensure the oracle is initialized correctly.
     /// - Verifies that the oracle can be set up successfully.
+    /// - Verifies that an `OracleSetup` event carrying the admin is emitted on success only.
     /// - Verifies that subsequent attempts to set up the oracle fail with `Error::OracleAlreadySet`.
     #[ink::test]
     fn test_setup_oracle() {
         let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
         assert_eq!(contract.setup_oracle(), Ok(()));
+        assert_eq!(last_event::<OracleSetup>(), OracleSetup { admin: accounts.alice });
+
+        let emitted = test::recorded_events().count();
         assert_eq!(contract.setup_oracle(), Err(Error::OracleAlreadySet));
+        assert_eq!(test::recorded_events().count(), emitted);
     }
 
     /// Tests the `mint_token` function to ensure NFTs can be minted correctly.
@@ -62,13 +135,2636 @@ mod tests {
     /// Tests the `get_oracle_data` function to ensure the oracle's state is reported correctly.
     /// - Verifies that the oracle's `current_index` starts at `0`.
     /// - Verifies that the `current_index` increments correctly after minting NFTs.
+    /// - Verifies that the setup flag, maximum supply and total supply are reported.
     #[ink::test]
     fn test_get_oracle_data() {
+        let mut contract = NFTMintingMachine::new_with_cap(10);
+        let data = contract.get_oracle_data();
+        assert_eq!(data.current_index, 0);
+        assert!(!data.setup);
+        assert_eq!(data.max_supply, Some(10));
+        assert_eq!(data.total_supply, 0);
+
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+        contract.burn(indices[0]).unwrap();
+        let data = contract.get_oracle_data();
+        assert_eq!(data.current_index, 2);
+        assert!(data.setup);
+        assert_eq!(data.total_supply, 1);
+
+        let contract = NFTMintingMachine::new();
+        assert_eq!(contract.get_oracle_data().max_supply, None);
+    }
+
+    /// Tests that `mint_token` emits a `Minted` event.
+    /// - Verifies that no event is emitted when minting fails.
+    /// - Verifies that the event carries the new index, owner and token name.
+    #[ink::test]
+    fn test_mint_emits_event() {
+        let mut contract = NFTMintingMachine::new();
+        assert_eq!(contract.mint_token(), Err(Error::OracleNotSetup));
+        assert_eq!(test::recorded_events().count(), 0);
+
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            last_event::<Minted>(),
+            Minted {
+                index: token_index,
+                owner: accounts.alice,
+                token_name: "NFT #1".into(),
+            }
+        );
+    }
+
+    /// Tests that `transfer_nft` emits a `Transfer` event.
+    /// - Verifies that the event carries the previous owner, the new owner and the index.
+    #[ink::test]
+    fn test_transfer_emits_event() {
         let mut contract = NFTMintingMachine::new();
-        assert_eq!(contract.get_oracle_data().current_index, 0);
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
 
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.transfer_nft(token_index, accounts.bob).unwrap();
+        assert_eq!(
+            last_event::<Transfer>(),
+            Transfer {
+                from: accounts.alice,
+                to: accounts.bob,
+                index: token_index,
+            }
+        );
+    }
+
+    /// Tests the `balance_of` function to ensure ownership counts stay consistent.
+    /// - Verifies that balances increase with each mint.
+    /// - Verifies that a transfer moves one unit of balance between accounts.
+    /// - Verifies that a self-transfer leaves the balance unchanged.
+    #[ink::test]
+    fn test_balance_of() {
+        let mut contract = NFTMintingMachine::new();
         contract.setup_oracle().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.balance_of(accounts.alice), 0);
+
+        let first = contract.mint_token().unwrap();
         contract.mint_token().unwrap();
-        assert_eq!(contract.get_oracle_data().current_index, 1);
+        contract.mint_token().unwrap();
+        assert_eq!(contract.balance_of(accounts.alice), 3);
+
+        contract.transfer_nft(first, accounts.bob).unwrap();
+        assert_eq!(contract.balance_of(accounts.alice), 2);
+        assert_eq!(contract.balance_of(accounts.bob), 1);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.transfer_nft(first, accounts.bob).unwrap();
+        assert_eq!(contract.balance_of(accounts.alice), 2);
+        assert_eq!(contract.balance_of(accounts.bob), 1);
+    }
+
+    /// Tests the `owner_of` function to ensure ownership lookups are correct.
+    /// - Verifies that the owner of a minted NFT is returned.
+    /// - Verifies that a non-existent index returns `Error::NFTNotFound`.
+    #[ink::test]
+    fn test_owner_of() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.alice));
+        assert_eq!(contract.owner_of(token_index + 1), Err(Error::NFTNotFound));
+    }
+
+    /// Tests the `new_with_cap` constructor to ensure the maximum supply is enforced.
+    /// - Verifies that the final mint at the cap succeeds.
+    /// - Verifies that the next mint fails with `Error::MaxSupplyReached`.
+    #[ink::test]
+    fn test_max_supply() {
+        let mut contract = NFTMintingMachine::new_with_cap(2);
+        contract.setup_oracle().unwrap();
+
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.mint_token(), Ok(2));
+        assert_eq!(contract.mint_token(), Err(Error::MaxSupplyReached));
+        assert_eq!(contract.get_oracle_data().current_index, 2);
+    }
+
+    /// Tests the `burn` function to ensure NFTs can be destroyed correctly.
+    /// - Verifies that burning fails for a non-owner and for a missing NFT.
+    /// - Verifies that the burned NFT is removed and the owner's balance decreases.
+    /// - Verifies that a `Transfer` event to the zero account is emitted.
+    /// - Verifies that burned indices are not reused by later mints.
+    #[ink::test]
+    fn test_burn() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.burn(token_index), Err(Error::NotOwner));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.burn(token_index + 1), Err(Error::NFTNotFound));
+        assert_eq!(contract.burn(token_index), Ok(()));
+        assert_eq!(contract.get_nft(token_index), None);
+        assert_eq!(contract.balance_of(accounts.alice), 0);
+        assert_eq!(
+            last_event::<Transfer>(),
+            Transfer {
+                from: accounts.alice,
+                to: [0u8; 32].into(),
+                index: token_index,
+            }
+        );
+
+        assert_eq!(contract.burn(token_index), Err(Error::NFTNotFound));
+        assert_eq!(contract.mint_token(), Ok(token_index + 1));
+    }
+
+    /// Tests the `mint_to` function to ensure the admin can mint to another account.
+    /// - Verifies that minting fails if the oracle is not set up.
+    /// - Verifies that the recipient, not the caller, owns the minted NFT.
+    /// - Verifies that a non-admin cannot mint to another account.
+    #[ink::test]
+    fn test_mint_to() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.mint_to(accounts.bob), Err(Error::OracleNotSetup));
+
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_to(accounts.bob).unwrap();
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.bob));
+        assert_eq!(contract.balance_of(accounts.alice), 0);
+        assert_eq!(contract.balance_of(accounts.bob), 1);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.mint_to(accounts.charlie), Err(Error::NotAdmin));
+    }
+
+    /// Tests the `mint_batch` function to ensure several NFTs can be minted at once.
+    /// - Verifies that the returned indices are contiguous and owned by the caller.
+    /// - Verifies that a batch exceeding the cap fails without minting anything.
+    #[ink::test]
+    fn test_mint_batch() {
+        let mut contract = NFTMintingMachine::new_with_cap(8);
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+
+        assert_eq!(contract.mint_batch(5), Ok(vec![2, 3, 4, 5, 6]));
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.balance_of(accounts.alice), 6);
+
+        assert_eq!(contract.mint_batch(3), Err(Error::MaxSupplyReached));
+        assert_eq!(contract.get_oracle_data().current_index, 6);
+        assert_eq!(contract.balance_of(accounts.alice), 6);
+    }
+
+    /// Tests the `total_supply` function to ensure it tracks live NFTs.
+    /// - Verifies that minting increases the supply.
+    /// - Verifies that burning decreases the supply while the oracle counter stays put.
+    #[ink::test]
+    fn test_total_supply() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.total_supply(), 0);
+
+        let indices = contract.mint_batch(3).unwrap();
+        assert_eq!(contract.total_supply(), 3);
+
+        contract.burn(indices[1]).unwrap();
+        assert_eq!(contract.total_supply(), 2);
+        assert_eq!(contract.get_oracle_data().current_index, 3);
+    }
+
+    /// Tests the `approve` and `transfer_from` functions to ensure delegated transfers work.
+    /// - Verifies that only the owner can approve a spender.
+    /// - Verifies that an unapproved caller cannot transfer the NFT.
+    /// - Verifies that the approved spender can transfer the NFT and the approval is cleared.
+    #[ink::test]
+    fn test_approve_and_transfer_from() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.approve(token_index, accounts.charlie), Err(Error::NotOwner));
+        assert_eq!(
+            contract.transfer_from(accounts.alice, accounts.charlie, token_index),
+            Err(Error::NotApproved)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.get_approved(token_index), None);
+        assert_eq!(contract.approve(token_index, accounts.bob), Ok(()));
+        assert_eq!(contract.get_approved(token_index), Some(accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.transfer_from(accounts.charlie, accounts.bob, token_index),
+            Err(Error::NotOwner)
+        );
+        assert_eq!(contract.transfer_from(accounts.alice, accounts.charlie, token_index), Ok(()));
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.charlie));
+        assert_eq!(contract.get_approved(token_index), None);
+    }
+
+    /// Tests the `set_approval_for_all` function to ensure operators can manage all NFTs.
+    /// - Verifies that an operator can transfer any of the owner's NFTs.
+    /// - Verifies that revoking the operator blocks further transfers.
+    #[ink::test]
+    fn test_set_approval_for_all() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(3).unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+        assert_eq!(contract.set_approval_for_all(accounts.bob, true), Ok(()));
+        assert!(contract.is_approved_for_all(accounts.alice, accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_from(accounts.alice, accounts.charlie, indices[0]), Ok(()));
+        assert_eq!(contract.transfer_from(accounts.alice, accounts.bob, indices[2]), Ok(()));
+        assert_eq!(contract.owner_of(indices[0]), Ok(accounts.charlie));
+        assert_eq!(contract.owner_of(indices[2]), Ok(accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_approval_for_all(accounts.bob, false), Ok(()));
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.transfer_from(accounts.alice, accounts.bob, indices[1]),
+            Err(Error::NotApproved)
+        );
+    }
+
+    /// Tests the `new_with_prefix` constructor to ensure custom token names are generated.
+    /// - Verifies that minted NFTs use the configured prefix followed by the index.
+    #[ink::test]
+    fn test_custom_prefix() {
+        let mut contract = NFTMintingMachine::new_with_prefix("CryptoPunk #".into()).unwrap();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let nft = contract.get_nft(token_index).unwrap();
+        assert_eq!(nft.token_name(), "CryptoPunk #1");
+    }
+
+    /// Tests the `mint_with_uri` and `token_uri` functions to ensure metadata URIs are stored.
+    /// - Verifies that the URI passed at mint time round-trips through storage.
+    /// - Verifies that `mint_token` produces an empty URI.
+    /// - Verifies that a non-existent index returns `Error::NFTNotFound`.
+    #[ink::test]
+    fn test_token_uri() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+
+        let with_uri = contract.mint_with_uri("ipfs://meta/1.json".into()).unwrap();
+        let without_uri = contract.mint_token().unwrap();
+        assert_eq!(contract.token_uri(with_uri), Ok("ipfs://meta/1.json".into()));
+        assert_eq!(contract.token_uri(without_uri), Ok(String::new()));
+        assert_eq!(contract.token_uri(without_uri + 1), Err(Error::NFTNotFound));
+    }
+
+    /// Tests the `set_base_uri` function to ensure derived token URIs follow the base URI.
+    /// - Verifies that only the admin can set the base URI.
+    /// - Verifies that changing the base URI is reflected in every derived token URI.
+    /// - Verifies that a URI stored at mint time takes precedence over the base URI.
+    #[ink::test]
+    fn test_base_uri() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+        let explicit = contract.mint_with_uri("ipfs://custom".into()).unwrap();
+
+        assert_eq!(contract.set_base_uri("ipfs://a/".into()), Ok(()));
+        assert_eq!(contract.token_uri(indices[0]), Ok("ipfs://a/1.json".into()));
+        assert_eq!(contract.token_uri(indices[1]), Ok("ipfs://a/2.json".into()));
+
+        assert_eq!(contract.set_base_uri("ipfs://b/".into()), Ok(()));
+        assert_eq!(contract.token_uri(indices[0]), Ok("ipfs://b/1.json".into()));
+        assert_eq!(contract.token_uri(indices[1]), Ok("ipfs://b/2.json".into()));
+        assert_eq!(contract.token_uri(explicit), Ok("ipfs://custom".into()));
+        assert_eq!(contract.token_uri(explicit + 1), Err(Error::NFTNotFound));
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_base_uri("ipfs://c/".into()), Err(Error::NotAdmin));
+    }
+
+    /// Tests the `transfer_admin` function to ensure admin rights can be handed over.
+    /// - Verifies that a non-admin cannot transfer admin rights.
+    /// - Verifies that an `AdminChanged` event is emitted.
+    /// - Verifies that the old admin can no longer call admin-only functions.
+    #[ink::test]
+    fn test_transfer_admin() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.transfer_admin(accounts.charlie), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.transfer_admin(accounts.bob), Ok(()));
+        assert_eq!(
+            last_event::<AdminChanged>(),
+            AdminChanged {
+                previous: accounts.alice,
+                new: accounts.bob,
+            }
+        );
+        assert_eq!(contract.setup_oracle(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.setup_oracle(), Ok(()));
+    }
+
+    /// Tests the `propose_admin` and `accept_admin` functions to ensure the two-step handover works.
+    /// - Verifies that a non-admin cannot propose a candidate.
+    /// - Verifies that a third party cannot accept the pending proposal.
+    /// - Verifies that the candidate becomes admin once it accepts.
+    #[ink::test]
+    fn test_two_step_admin_handover() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.propose_admin(accounts.charlie), Err(Error::NotAdmin));
+        assert_eq!(contract.accept_admin(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.propose_admin(accounts.bob), Ok(()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.accept_admin(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.accept_admin(), Ok(()));
+        assert_eq!(
+            last_event::<AdminChanged>(),
+            AdminChanged {
+                previous: accounts.alice,
+                new: accounts.bob,
+            }
+        );
+        assert_eq!(contract.accept_admin(), Err(Error::NotAdmin));
+        assert_eq!(contract.setup_oracle(), Ok(()));
+    }
+
+    /// Tests the `pause` and `unpause` functions to ensure minting can be halted.
+    /// - Verifies that only the admin can pause and unpause.
+    /// - Verifies that every mint variant fails with `Error::Paused` while paused.
+    /// - Verifies that transfers remain allowed while paused.
+    /// - Verifies that minting resumes after unpausing.
+    #[ink::test]
+    fn test_pause() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.pause(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.pause(), Ok(()));
+        assert_eq!(contract.mint_token(), Err(Error::Paused));
+        assert_eq!(contract.mint_batch(2), Err(Error::Paused));
+        assert_eq!(contract.mint_to(accounts.bob), Err(Error::Paused));
+        assert_eq!(contract.transfer_nft(token_index, accounts.bob), Ok(()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.unpause(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.unpause(), Ok(()));
+        assert_eq!(contract.mint_token(), Ok(token_index + 1));
+    }
+
+    /// Tests the `new_with_price` constructor to ensure paid minting is enforced.
+    /// - Verifies that minting fails with `Error::InsufficientPayment` when underpaying.
+    /// - Verifies that minting succeeds when the price is paid.
+    /// - Verifies that a batch must cover the price of every NFT.
+    #[ink::test]
+    fn test_mint_price() {
+        let mut contract = NFTMintingMachine::new_with_price(100);
+        contract.setup_oracle().unwrap();
+
+        test::set_value_transferred::<DefaultEnvironment>(99);
+        assert_eq!(contract.mint_token(), Err(Error::InsufficientPayment));
+
+        test::set_value_transferred::<DefaultEnvironment>(100);
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.mint_batch(2), Err(Error::InsufficientPayment));
+
+        test::set_value_transferred::<DefaultEnvironment>(200);
+        assert_eq!(contract.mint_batch(2), Ok(vec![2, 3]));
+    }
+
+    /// Tests the `withdraw` function to ensure collected mint fees can be paid out.
+    /// - Verifies that a non-admin cannot withdraw.
+    /// - Verifies that withdrawing more than the contract balance fails.
+    /// - Verifies that the admin receives the collected amount.
+    #[ink::test]
+    fn test_withdraw() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_price(100);
+        contract.setup_oracle().unwrap();
+
+        test::transfer_in::<DefaultEnvironment>(100);
+        contract.mint_token().unwrap();
+        let admin_balance = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.withdraw(100), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.withdraw(101), Err(Error::InsufficientBalance));
+        assert_eq!(contract.withdraw(100), Ok(()));
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+            Ok(admin_balance + 100)
+        );
+        assert_eq!(test::get_account_balance::<DefaultEnvironment>(accounts.django), Ok(0));
+    }
+
+    /// Tests the `new_with_mint_limit` constructor to ensure per-account limits are enforced.
+    /// - Verifies that the N+1th mint from the same account fails with `Error::MintLimitReached`.
+    /// - Verifies that a different account can keep minting.
+    #[ink::test]
+    fn test_per_account_limit() {
+        let mut contract = NFTMintingMachine::new_with_mint_limit(2);
+        contract.setup_oracle().unwrap();
+
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.mint_token(), Ok(2));
+        assert_eq!(contract.mint_token(), Err(Error::MintLimitReached));
+        assert_eq!(contract.mint_batch(1), Err(Error::MintLimitReached));
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_batch(3), Err(Error::MintLimitReached));
+        assert_eq!(contract.mint_batch(2), Ok(vec![3, 4]));
+    }
+
+    /// Tests the allowlist functions to ensure minting can be gated.
+    /// - Verifies that only the admin can manage the allowlist.
+    /// - Verifies that unlisted callers are rejected while the gate is enabled.
+    /// - Verifies that listed callers can mint while the gate is enabled.
+    /// - Verifies that minting is open to everyone once the gate is disabled.
+    #[ink::test]
+    fn test_allowlist() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_allowlist_only(true), Err(Error::NotAdmin));
+        assert_eq!(contract.add_to_allowlist(accounts.charlie), Err(Error::NotAdmin));
+        assert_eq!(contract.remove_from_allowlist(accounts.charlie), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_allowlist_only(true), Ok(()));
+        assert_eq!(contract.add_to_allowlist(accounts.bob), Ok(()));
+        assert_eq!(contract.mint_token(), Err(Error::NotAllowlisted));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_token(), Ok(1));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.remove_from_allowlist(accounts.bob), Ok(()));
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_token(), Err(Error::NotAllowlisted));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_allowlist_only(false), Ok(()));
+        assert_eq!(contract.mint_token(), Ok(2));
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_token(), Ok(3));
+    }
+
+    /// Tests the `mint_with_proof` function to ensure Merkle allowlisting works.
+    /// - Verifies that minting fails with `Error::InvalidProof` while no root is set.
+    /// - Verifies that valid proofs for different leaves of a hand-built tree are accepted.
+    /// - Verifies that an account outside the tree is rejected.
+    #[ink::test]
+    fn test_mint_with_proof() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (a, b, c, d) = (
+            leaf(accounts.alice),
+            leaf(accounts.bob),
+            leaf(accounts.charlie),
+            leaf(accounts.django),
+        );
+        let (ab, cd) = (hash_pair(a, b), hash_pair(c, d));
+        let root = hash_pair(ab, cd);
+
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_with_proof(vec![b, cd]), Err(Error::InvalidProof));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_allowlist_root(Some(root)), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_allowlist_root(Some(root)), Ok(()));
+        assert_eq!(contract.mint_with_proof(vec![b, cd]), Ok(1));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.mint_with_proof(vec![d, ab]), Ok(2));
+        assert_eq!(contract.mint_with_proof(vec![b, cd]), Err(Error::InvalidProof));
+        assert_eq!(contract.owner_of(2), Ok(accounts.charlie));
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(contract.mint_with_proof(vec![d, ab]), Err(Error::InvalidProof));
+    }
+
+    /// Tests the `set_mint_window` function to ensure minting is limited to the window.
+    /// - Verifies that only the admin can set the window.
+    /// - Verifies that minting fails with `Error::MintNotActive` before and after the window.
+    /// - Verifies that minting succeeds during the window.
+    /// - Verifies that an unset bound leaves that side open.
+    #[ink::test]
+    fn test_mint_window() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_mint_window(Some(1_000), Some(2_000)), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_mint_window(Some(1_000), Some(2_000)), Ok(()));
+
+        test::set_block_timestamp::<DefaultEnvironment>(999);
+        assert_eq!(contract.mint_token(), Err(Error::MintNotActive));
+        test::set_block_timestamp::<DefaultEnvironment>(1_000);
+        assert_eq!(contract.mint_token(), Ok(1));
+        test::set_block_timestamp::<DefaultEnvironment>(1_999);
+        assert_eq!(contract.mint_token(), Ok(2));
+        test::set_block_timestamp::<DefaultEnvironment>(2_000);
+        assert_eq!(contract.mint_token(), Err(Error::MintNotActive));
+
+        assert_eq!(contract.set_mint_window(Some(1_000), None), Ok(()));
+        test::set_block_timestamp::<DefaultEnvironment>(u64::MAX);
+        assert_eq!(contract.mint_token(), Ok(3));
+    }
+
+    /// Tests the `tokens_of_owner` function to ensure holdings can be enumerated.
+    /// - Verifies that every minted NFT is listed for its owner.
+    /// - Verifies that transferred NFTs move to the new owner's list.
+    /// - Verifies that burned NFTs are removed from the list.
+    #[ink::test]
+    fn test_tokens_of_owner() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(3).unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.tokens_of_owner(accounts.alice), indices);
+        assert!(contract.tokens_of_owner(accounts.bob).is_empty());
+
+        contract.transfer_nft(indices[0], accounts.bob).unwrap();
+        let mut remaining = contract.tokens_of_owner(accounts.alice);
+        remaining.sort_unstable();
+        assert_eq!(remaining, vec![indices[1], indices[2]]);
+        assert_eq!(contract.tokens_of_owner(accounts.bob), vec![indices[0]]);
+
+        contract.burn(indices[2]).unwrap();
+        assert_eq!(contract.tokens_of_owner(accounts.alice), vec![indices[1]]);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.transfer_nft(indices[0], accounts.alice).unwrap();
+        assert!(contract.tokens_of_owner(accounts.bob).is_empty());
+        let mut holdings = contract.tokens_of_owner(accounts.alice);
+        holdings.sort_unstable();
+        assert_eq!(holdings, vec![indices[0], indices[1]]);
+    }
+
+    /// Tests the `tokens_paginated` function to ensure collections can be paged through.
+    /// - Verifies that a window in the middle of the range skips burned indices.
+    /// - Verifies that a window running past the end returns only existing NFTs.
+    /// - Verifies that the page size is capped.
+    /// - Verifies that the returned cursor continues where the page stopped.
+    #[ink::test]
+    fn test_tokens_paginated() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        contract.mint_batch(6).unwrap();
+        contract.burn(3).unwrap();
+
+        let (page, next) = contract.tokens_paginated(2, 3);
+        let indices: Vec<u64> = page.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![2, 4, 5]);
+        assert_eq!(page[1].1.token_name(), "NFT #4");
+        assert_eq!(next, Some(6));
+
+        let (page, next) = contract.tokens_paginated(5, 10);
+        let indices: Vec<u64> = page.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![5, 6]);
+        assert_eq!(next, None);
+        assert_eq!(contract.tokens_paginated(7, 10), (Vec::new(), None));
+
+        for _ in 0..3 {
+            contract.mint_batch(40).unwrap();
+        }
+        let (page, next) = contract.tokens_paginated(4, u64::MAX);
+        assert_eq!(page.len(), 100);
+        assert_eq!(next, Some(104));
+    }
+
+    /// Tests that `tokens_paginated` bounds the indices it scans over a sparse collection.
+    /// - Verifies that a page over a large gap comes back empty with a cursor past the scan.
+    /// - Verifies that starting at the cursor eventually reaches the minted NFT.
+    #[ink::test]
+    fn test_tokens_paginated_sparse() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle_from(200_000).unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        assert_eq!(contract.tokens_paginated(0, 10), (Vec::new(), Some(MAX_PAGE_SIZE)));
+        let (page, next) = contract.tokens_paginated(token_index - 50, 10);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].0, token_index);
+        assert_eq!(next, None);
+    }
+
+    /// Tests the `new_soulbound` constructor to ensure NFTs cannot move.
+    /// - Verifies that minting and burning still work.
+    /// - Verifies that transfers and approvals fail with `Error::NonTransferable`.
+    #[ink::test]
+    fn test_soulbound() {
+        let mut contract = NFTMintingMachine::new_soulbound();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.transfer_nft(token_index, accounts.bob), Err(Error::NonTransferable));
+        assert_eq!(contract.approve(token_index, accounts.bob), Err(Error::NonTransferable));
+        assert_eq!(
+            contract.transfer_from(accounts.alice, accounts.bob, token_index),
+            Err(Error::NonTransferable)
+        );
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.alice));
+
+        assert_eq!(contract.burn(token_index), Ok(()));
+    }
+
+    /// Tests the `reveal` function to ensure placeholder metadata is replaced.
+    /// - Verifies that every NFT returns the placeholder while unrevealed.
+    /// - Verifies that only the admin can set the placeholder and reveal.
+    /// - Verifies that the real URIs are returned after the reveal.
+    #[ink::test]
+    fn test_reveal() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        contract.set_base_uri("ipfs://real/".into()).unwrap();
+        contract.set_placeholder_uri("ipfs://hidden.json".into()).unwrap();
+        let first = contract.mint_token().unwrap();
+        let second = contract.mint_with_uri("ipfs://custom".into()).unwrap();
+
+        assert_eq!(contract.token_uri(first), Ok("ipfs://hidden.json".into()));
+        assert_eq!(contract.token_uri(second), Ok("ipfs://hidden.json".into()));
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_placeholder_uri("ipfs://other".into()), Err(Error::NotAdmin));
+        assert_eq!(contract.reveal(), Err(Error::NotAdmin));
+        assert_eq!(contract.token_uri(first), Ok("ipfs://hidden.json".into()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.reveal(), Ok(()));
+        assert_eq!(contract.token_uri(first), Ok("ipfs://real/1.json".into()));
+        assert_eq!(contract.token_uri(second), Ok("ipfs://custom".into()));
+    }
+
+    /// Tests the `new_with_royalty` constructor and `royalty_info` function.
+    /// - Verifies that a rate above 10000 basis points is rejected.
+    /// - Verifies the royalty amount for a couple of sale prices.
+    /// - Verifies that a zero-royalty collection owes nothing.
+    #[ink::test]
+    fn test_royalty_info() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            NFTMintingMachine::new_with_royalty(accounts.bob, 10_001).err(),
+            Some(Error::InvalidRoyalty)
+        );
+
+        let contract = NFTMintingMachine::new_with_royalty(accounts.bob, 250).unwrap();
+        assert_eq!(contract.royalty_info(1, 10_000), (accounts.bob, 250));
+        assert_eq!(contract.royalty_info(1, 1_234_567), (accounts.bob, 30_864));
+        assert_eq!(contract.royalty_info(1, u128::MAX), (accounts.bob, u128::MAX / 40));
+
+        let contract = NFTMintingMachine::new_with_royalty(accounts.bob, 0).unwrap();
+        assert_eq!(contract.royalty_info(1, 10_000), (accounts.bob, 0));
+    }
+
+    /// Tests the `supports_interface` function to ensure capabilities are reported.
+    /// - Verifies that every documented interface ID is supported.
+    /// - Verifies that the IDs are derived from the contract's own message selectors.
+    /// - Verifies that an unknown interface ID is not supported.
+    #[ink::test]
+    fn test_supports_interface() {
+        let contract = NFTMintingMachine::new();
+        assert!(contract.supports_interface(INTERFACE_NFT));
+        assert!(contract.supports_interface(INTERFACE_METADATA));
+        assert!(contract.supports_interface(INTERFACE_ENUMERABLE));
+        assert!(contract.supports_interface(INTERFACE_ROYALTY));
+        assert!(!contract.supports_interface([0xff, 0xff, 0xff, 0xff]));
+
+        assert_eq!(INTERFACE_ROYALTY, ink::selector_bytes!("royalty_info"));
+        let metadata = [
+            ink::selector_bytes!("name"),
+            ink::selector_bytes!("symbol"),
+            ink::selector_bytes!("token_uri"),
+        ];
+        let expected: [u8; 4] = core::array::from_fn(|byte| {
+            metadata.iter().fold(0, |id, selector| id ^ selector[byte])
+        });
+        assert_eq!(INTERFACE_METADATA, expected);
+    }
+
+    /// Tests the `new_with_collection` constructor to ensure the collection identity is stored.
+    /// - Verifies that the name and symbol round-trip.
+    /// - Verifies that `new()` leaves both empty.
+    #[ink::test]
+    fn test_name_and_symbol() {
+        let contract = NFTMintingMachine::new_with_collection("Crypto Punks".into(), "PUNK".into());
+        assert_eq!(contract.name(), "Crypto Punks");
+        assert_eq!(contract.symbol(), "PUNK");
+
+        let contract = NFTMintingMachine::new();
+        assert_eq!(contract.name(), "");
+        assert_eq!(contract.symbol(), "");
+    }
+
+    /// Tests the `freeze_metadata` function to ensure metadata can be locked.
+    /// - Verifies that only the admin can freeze metadata.
+    /// - Verifies that URI changes fail with `Error::MetadataFrozen` once frozen.
+    /// - Verifies that existing token URIs are unaffected.
+    #[ink::test]
+    fn test_freeze_metadata() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        contract.set_base_uri("ipfs://a/".into()).unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.freeze_metadata(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.freeze_metadata(), Ok(()));
+        assert_eq!(contract.set_base_uri("ipfs://b/".into()), Err(Error::MetadataFrozen));
+        assert_eq!(contract.set_placeholder_uri("ipfs://hidden".into()), Err(Error::MetadataFrozen));
+        assert_eq!(contract.token_uri(token_index), Ok("ipfs://a/1.json".into()));
+    }
+
+    /// Tests the `safe_transfer` function for plain account recipients.
+    /// - Verifies that it behaves like `transfer_nft` when the recipient is not a contract.
+    /// - Verifies that a non-owner cannot transfer the NFT.
+    #[ink::test]
+    fn test_safe_transfer_to_account() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.safe_transfer(accounts.bob, token_index, vec![1, 2, 3]), Ok(()));
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.bob));
+        assert_eq!(
+            last_event::<Transfer>(),
+            Transfer {
+                from: accounts.alice,
+                to: accounts.bob,
+                index: token_index,
+            }
+        );
+
+        assert_eq!(
+            contract.safe_transfer(accounts.charlie, token_index, Vec::new()),
+            Err(Error::NotOwner)
+        );
+    }
+
+    /// Tests the `reset_oracle` function to ensure a new numbering series can be started.
+    /// - Verifies that a non-admin cannot reset the oracle.
+    /// - Verifies that resetting is blocked while NFTs exist.
+    /// - Verifies that a clean reset restarts numbering from the given start.
+    #[ink::test]
+    fn test_reset_oracle() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.reset_oracle(1_000), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.reset_oracle(1_000), Err(Error::CollectionNotEmpty));
+
+        contract.burn(token_index).unwrap();
+        assert_eq!(contract.reset_oracle(1_000), Ok(()));
+        assert_eq!(contract.get_oracle_data().current_index, 1_000);
+        assert_eq!(contract.mint_token(), Ok(1_001));
+    }
+
+    /// Tests that `reset_oracle` cannot lift the maximum supply.
+    /// - Verifies that after burning everything and resetting, minting past the cap fails with
+    ///   `Error::MaxSupplyReached`.
+    /// - Verifies that `remaining_supply` reports the lifetime allowance left.
+    #[ink::test]
+    fn test_reset_oracle_respects_cap() {
+        let mut contract = NFTMintingMachine::new_with_cap(3);
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+        for index in indices {
+            contract.burn(index).unwrap();
+        }
+
+        assert_eq!(contract.reset_oracle(0), Ok(()));
+        assert_eq!(contract.remaining_supply(), Some(1));
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.mint_token(), Err(Error::MaxSupplyReached));
+        assert_eq!(contract.remaining_supply(), Some(0));
+        assert_eq!(contract.progress_bps(), Some(10_000));
+    }
+
+    /// Tests that `reset_oracle` keeps public numbering out of the reserved range.
+    /// - Verifies that a reset below the reserved range restarts right after it.
+    /// - Verifies that reserved and public mints then get distinct indices and owners.
+    #[ink::test]
+    fn test_reset_oracle_respects_reserve() {
+        let mut contract = NFTMintingMachine::new_with_reserve(3);
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.reset_oracle(0), Ok(()));
+        assert_eq!(contract.get_oracle_data().current_index, 3);
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.mint_token(), Ok(4));
+        assert_eq!(contract.mint_reserved(accounts.bob, 1), Ok(vec![1]));
+        assert_eq!(contract.owner_of(4), Ok(accounts.alice));
+        assert_eq!(contract.owner_of(1), Ok(accounts.bob));
+        assert_eq!(contract.tokens_of_owner(accounts.alice), vec![4]);
+        assert_eq!(contract.total_supply(), 2);
+    }
+
+    /// Tests the `exists` function to ensure existence checks are correct.
+    /// - Verifies that a minted index exists.
+    /// - Verifies that burned and unminted indices do not exist.
+    #[ink::test]
+    fn test_exists() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+        contract.burn(indices[1]).unwrap();
+
+        assert!(contract.exists(indices[0]));
+        assert!(!contract.exists(indices[1]));
+        assert!(!contract.exists(indices[1] + 1));
+    }
+
+    /// Tests the `new_with_reserve` constructor and `mint_reserved` function.
+    /// - Verifies that public indices start past the reserved range.
+    /// - Verifies that only the admin can mint from the reserved range.
+    /// - Verifies that reserved NFTs are minted in order to the recipient.
+    /// - Verifies that overdrawing the reserve fails with `Error::ReservedExhausted`.
+    /// - Verifies that minting to the all-zero account fails with `Error::ZeroAddress`.
+    #[ink::test]
+    fn test_mint_reserved() {
+        let mut contract = NFTMintingMachine::new_with_reserve(3);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.mint_reserved(accounts.bob, 1), Err(Error::OracleNotSetup));
+
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_token(), Ok(4));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.mint_reserved(accounts.charlie, 1), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.mint_reserved(accounts.bob, 2), Ok(vec![1, 2]));
+        assert_eq!(contract.reserved_remaining(), 1);
+        assert_eq!(contract.owner_of(2), Ok(accounts.bob));
+        assert_eq!(contract.get_nft(2).unwrap().token_name(), "NFT #2");
+
+        assert_eq!(contract.mint_reserved(accounts.bob, 2), Err(Error::ReservedExhausted));
+        let zero = AccountId::from([0u8; 32]);
+        assert_eq!(contract.mint_reserved(zero, 1), Err(Error::ZeroAddress));
+        assert_eq!(contract.reserved_remaining(), 1);
+        assert_eq!(contract.mint_reserved(accounts.bob, 1), Ok(vec![3]));
+        assert_eq!(contract.reserved_remaining(), 0);
+        assert_eq!(contract.mint_token(), Ok(5));
+        assert_eq!(contract.total_supply(), 5);
+    }
+
+    /// Tests the `grant_role` and `revoke_role` functions.
+    /// - Verifies that only the admin can grant roles.
+    /// - Verifies that a `ROLE_MINTER` holder can `mint_to` but cannot `pause`.
+    /// - Verifies that revoking the role removes the permission again.
+    #[ink::test]
+    fn test_roles() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        assert!(contract.has_role(accounts.alice, ROLE_PAUSER));
+        assert!(!contract.has_role(accounts.bob, ROLE_MINTER));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.grant_role(accounts.bob, ROLE_MINTER), Err(Error::NotAdmin));
+        assert_eq!(contract.mint_to(accounts.charlie), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.grant_role(accounts.bob, ROLE_MINTER).unwrap();
+        assert!(contract.has_role(accounts.bob, ROLE_MINTER));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let index = contract.mint_to(accounts.charlie).unwrap();
+        assert_eq!(contract.owner_of(index), Ok(accounts.charlie));
+        assert_eq!(contract.pause(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.revoke_role(accounts.bob, ROLE_MINTER).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_to(accounts.charlie), Err(Error::NotAdmin));
+    }
+
+    /// Tests the `mint_with_signature` function with a known keypair.
+    /// - Verifies that only the admin can set the authorizer.
+    /// - Verifies that a signature by the authorizer mints to the recipient.
+    /// - Verifies that reusing a nonce fails with `Error::NonceUsed`.
+    /// - Verifies that a foreign or mismatched signature fails with `Error::InvalidSignature`.
+    /// - Verifies that a signature made for another contract fails with
+    ///   `Error::InvalidSignature`.
+    /// - Verifies that minting to the zero account fails with `Error::ZeroAddress`.
+    #[ink::test]
+    fn test_mint_with_signature() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let authorizer = [0x11u8; 32];
+        let stranger = [0x22u8; 32];
+        contract.setup_oracle().unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_authorizer(accounts.bob), Err(Error::NotAdmin));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_authorizer(ecdsa_account(&authorizer)).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let this = test::callee::<DefaultEnvironment>();
+        let signature = sign_mint(&authorizer, this, accounts.bob, 7);
+        let index = contract.mint_with_signature(accounts.bob, 7, signature).unwrap();
+        assert_eq!(contract.owner_of(index), Ok(accounts.bob));
+        assert_eq!(
+            contract.mint_with_signature(accounts.bob, 7, signature),
+            Err(Error::NonceUsed)
+        );
+
+        let signature = sign_mint(&authorizer, this, accounts.bob, 8);
+        assert_eq!(
+            contract.mint_with_signature(accounts.charlie, 8, signature),
+            Err(Error::InvalidSignature)
+        );
+        let signature = sign_mint(&stranger, this, accounts.bob, 9);
+        assert_eq!(
+            contract.mint_with_signature(accounts.bob, 9, signature),
+            Err(Error::InvalidSignature)
+        );
+        let signature = sign_mint(&authorizer, accounts.django, accounts.bob, 10);
+        assert_eq!(
+            contract.mint_with_signature(accounts.bob, 10, signature),
+            Err(Error::InvalidSignature)
+        );
+        let zero = AccountId::from([0u8; 32]);
+        let signature = sign_mint(&authorizer, this, zero, 11);
+        assert_eq!(contract.mint_with_signature(zero, 11, signature), Err(Error::ZeroAddress));
+        assert_eq!(contract.total_supply(), 1);
+    }
+
+    /// Tests the `combine` function to ensure two NFTs can be burned into a new one.
+    /// - Verifies that combining fails with `Error::NotOwner` if the caller doesn't own both.
+    /// - Verifies that a failed combine leaves both NFTs in place.
+    /// - Verifies that combining burns both inputs and mints a new NFT named after them.
+    #[ink::test]
+    fn test_combine() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+        let foreign = contract.mint_to(accounts.bob).unwrap();
+
+        assert_eq!(contract.combine(indices[0], foreign), Err(Error::NotOwner));
+        assert_eq!(contract.combine(indices[0], indices[0]), Err(Error::NFTNotFound));
+        assert!(contract.exists(indices[0]));
+        assert_eq!(contract.total_supply(), 3);
+
+        let combined = contract.combine(indices[0], indices[1]).unwrap();
+        assert!(!contract.exists(indices[0]));
+        assert!(!contract.exists(indices[1]));
+        assert_eq!(contract.owner_of(combined), Ok(accounts.alice));
+        assert_eq!(contract.get_nft(combined).unwrap().token_name(), "NFT #1 + NFT #2");
+        assert_eq!(contract.balance_of(accounts.alice), 1);
+        assert_eq!(contract.total_supply(), 2);
+    }
+
+    /// Tests the `remaining_supply` function to ensure it reports the mintable supply left.
+    /// - Verifies that a partially minted collection reports the difference to the cap.
+    /// - Verifies that a fully minted collection reports zero.
+    /// - Verifies that an unlimited collection reports `None`.
+    #[ink::test]
+    fn test_remaining_supply() {
+        let mut contract = NFTMintingMachine::new_with_cap(3);
+        assert_eq!(contract.remaining_supply(), Some(3));
+        contract.setup_oracle().unwrap();
+
+        contract.mint_token().unwrap();
+        assert_eq!(contract.remaining_supply(), Some(2));
+        contract.mint_batch(2).unwrap();
+        assert_eq!(contract.remaining_supply(), Some(0));
+
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+        assert_eq!(contract.remaining_supply(), None);
+    }
+
+    /// Tests the `airdrop` function to ensure NFTs are minted to many recipients at once.
+    /// - Verifies that a non-admin cannot airdrop.
+    /// - Verifies that an airdrop breaching the cap fails without minting anything.
+    /// - Verifies that more than `MAX_LOOKUP_SIZE` recipients fail with `Error::TooManyItems`.
+    /// - Verifies that each recipient owns exactly one NFT, with indices in recipient order.
+    #[ink::test]
+    fn test_airdrop() {
+        let mut contract = NFTMintingMachine::new_with_cap(3);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let recipients = vec![accounts.bob, accounts.charlie, accounts.django];
+        contract.setup_oracle().unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.airdrop(recipients.clone()), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut too_many = recipients.clone();
+        too_many.push(accounts.eve);
+        assert_eq!(contract.airdrop(too_many), Err(Error::MaxSupplyReached));
+        assert_eq!(contract.total_supply(), 0);
+        let oversized = vec![accounts.bob; MAX_LOOKUP_SIZE + 1];
+        assert_eq!(contract.airdrop(oversized), Err(Error::TooManyItems));
+
+        assert_eq!(contract.airdrop(recipients.clone()), Ok(vec![1, 2, 3]));
+        for (index, recipient) in (1..).zip(recipients) {
+            assert_eq!(contract.balance_of(recipient), 1);
+            assert_eq!(contract.owner_of(index), Ok(recipient));
+        }
+    }
+
+    /// Tests the `minted_at` function to ensure the mint timestamp is recorded per NFT.
+    /// - Verifies that each NFT stores the block timestamp of its mint.
+    /// - Verifies that querying a missing NFT fails with `Error::NFTNotFound`.
+    #[ink::test]
+    fn test_minted_at() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+
+        test::set_block_timestamp::<DefaultEnvironment>(1_000);
+        let first = contract.mint_token().unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(2_500);
+        let second = contract.mint_token().unwrap();
+
+        assert_eq!(contract.minted_at(first), Ok(1_000));
+        assert_eq!(contract.minted_at(second), Ok(2_500));
+        assert_eq!(contract.minted_at(second + 1), Err(Error::NFTNotFound));
+    }
+
+    /// Tests that ownership changes clear stale approvals.
+    /// - Verifies that after `transfer_nft` the previously approved spender cannot move the NFT.
+    /// - Verifies that `burn` removes the approval of the burned NFT.
+    #[ink::test]
+    fn test_transfer_clears_approval() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+
+        contract.approve(indices[0], accounts.bob).unwrap();
+        contract.transfer_nft(indices[0], accounts.charlie).unwrap();
+        assert_eq!(contract.get_approved(indices[0]), None);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.transfer_from(accounts.charlie, accounts.bob, indices[0]),
+            Err(Error::NotApproved)
+        );
+        assert_eq!(contract.owner_of(indices[0]), Ok(accounts.charlie));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.approve(indices[1], accounts.bob).unwrap();
+        contract.burn(indices[1]).unwrap();
+        assert_eq!(contract.get_approved(indices[1]), None);
+    }
+
+    /// Tests the `set_contract_uri` and `contract_uri` functions.
+    /// - Verifies that a non-admin cannot set the contract URI.
+    /// - Verifies that the contract URI round-trips.
+    /// - Verifies that the contract URI cannot be changed once metadata is frozen.
+    #[ink::test]
+    fn test_contract_uri() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.contract_uri(), "");
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_contract_uri("ipfs://evil".into()), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_contract_uri("ipfs://collection.json".into()).unwrap();
+        assert_eq!(contract.contract_uri(), "ipfs://collection.json");
+
+        contract.freeze_metadata().unwrap();
+        assert_eq!(contract.set_contract_uri("ipfs://b".into()), Err(Error::MetadataFrozen));
+        assert_eq!(contract.contract_uri(), "ipfs://collection.json");
+    }
+
+    /// Tests the `mints_by` function to ensure cumulative mints are tracked per account.
+    /// - Verifies that each account's count is independent.
+    /// - Verifies that transferring NFTs away doesn't lower the count.
+    #[ink::test]
+    fn test_mints_by() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+
+        let indices = contract.mint_batch(2).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.mint_token().unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.transfer_nft(indices[0], accounts.charlie).unwrap();
+        assert_eq!(contract.mints_by(accounts.alice), 2);
+        assert_eq!(contract.mints_by(accounts.bob), 1);
+        assert_eq!(contract.mints_by(accounts.charlie), 0);
+    }
+
+    /// Tests the `owners_of` function to ensure owners are resolved in bulk.
+    /// - Verifies that existing NFTs resolve to their owners and missing ones to `None`.
+    /// - Verifies that the input order is preserved.
+    /// - Verifies that oversized lookups fail with `Error::TooManyItems`.
+    #[ink::test]
+    fn test_owners_of() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let mine = contract.mint_token().unwrap();
+        let theirs = contract.mint_to(accounts.bob).unwrap();
+
+        assert_eq!(
+            contract.owners_of(vec![theirs, 99, mine]),
+            Ok(vec![Some(accounts.bob), None, Some(accounts.alice)])
+        );
+        assert_eq!(
+            contract.owners_of(vec![mine; MAX_LOOKUP_SIZE + 1]),
+            Err(Error::TooManyItems)
+        );
+    }
+
+    /// Tests the `rename` function to ensure owners can personalize token names.
+    /// - Verifies that the owner can rename the NFT.
+    /// - Verifies that a name longer than `MAX_NAME_LENGTH` fails with `Error::NameTooLong`.
+    /// - Verifies that a non-owner cannot rename the NFT.
+    /// - Verifies that renaming fails with `Error::MetadataFrozen` once metadata is frozen.
+    #[ink::test]
+    fn test_rename() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        assert_eq!(contract.rename(token_index, "Sparky".into()), Ok(()));
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "Sparky");
+        assert_eq!(
+            contract.rename(token_index, "x".repeat(MAX_NAME_LENGTH + 1)),
+            Err(Error::NameTooLong)
+        );
+        assert_eq!(contract.rename(token_index + 1, "Ghost".into()), Err(Error::NFTNotFound));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.rename(token_index, "Stolen".into()), Err(Error::NotOwner));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.freeze_metadata().unwrap();
+        assert_eq!(contract.rename(token_index, "Later".into()), Err(Error::MetadataFrozen));
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "Sparky");
+    }
+
+    /// Tests the `checkpoint` function to ensure supply snapshots are emitted.
+    /// - Verifies that only the admin can emit a checkpoint.
+    /// - Verifies that the `SupplyCheckpoint` event carries the counter, supply and block number.
+    #[ink::test]
+    fn test_checkpoint() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(3).unwrap();
+        contract.burn(indices[0]).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.checkpoint(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_number::<DefaultEnvironment>(42);
+        assert_eq!(contract.checkpoint(), Ok(()));
+        assert_eq!(
+            last_event::<SupplyCheckpoint>(),
+            SupplyCheckpoint {
+                current_index: 3,
+                total_supply: 2,
+                block_number: 42,
+            }
+        );
+    }
+
+    /// Tests that NFTs can't be minted or transferred to the all-zero account.
+    /// - Verifies that `mint_to`, `airdrop`, `transfer_nft` and `transfer_from` fail with
+    ///   `Error::ZeroAddress`.
+    /// - Verifies that a rejected airdrop mints nothing.
+    #[ink::test]
+    fn test_zero_address_rejected() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let zero = AccountId::from([0u8; 32]);
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        assert_eq!(contract.mint_to(zero), Err(Error::ZeroAddress));
+        assert_eq!(contract.airdrop(vec![accounts.bob, zero]), Err(Error::ZeroAddress));
+        assert_eq!(contract.total_supply(), 1);
+        assert_eq!(contract.transfer_nft(token_index, zero), Err(Error::ZeroAddress));
+        assert_eq!(
+            contract.transfer_from(accounts.alice, zero, token_index),
+            Err(Error::ZeroAddress)
+        );
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.alice));
+    }
+
+    /// Tests the `set_max_batch` function and the `mint_batch` limit.
+    /// - Verifies that a batch at the limit succeeds and one over it fails with
+    ///   `Error::TooManyItems`.
+    /// - Verifies that only the admin can change the limit, and that it is then enforced.
+    #[ink::test]
+    fn test_max_batch() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+
+        assert_eq!(contract.mint_batch(DEFAULT_MAX_BATCH + 1), Err(Error::TooManyItems));
+        assert_eq!(contract.mint_batch(DEFAULT_MAX_BATCH).unwrap().len(), 50);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_max_batch(100), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_max_batch(2).unwrap();
+        assert_eq!(contract.mint_batch(3), Err(Error::TooManyItems));
+        assert_eq!(contract.mint_batch(2).unwrap().len(), 2);
+        assert_eq!(contract.total_supply(), 52);
+    }
+
+    /// Tests the `transfer_many` function to ensure NFTs can be moved in bulk.
+    /// - Verifies that a list containing an NFT the caller doesn't own moves nothing.
+    /// - Verifies that listing an NFT twice is rejected.
+    /// - Verifies that all listed NFTs move to the recipient with a `Transfer` event each.
+    #[ink::test]
+    fn test_transfer_many() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(3).unwrap();
+        let foreign = contract.mint_to(accounts.bob).unwrap();
+
+        let mut mixed = indices.clone();
+        mixed.push(foreign);
+        assert_eq!(contract.transfer_many(mixed, accounts.charlie), Err(Error::NotOwner));
+        let twice = vec![indices[0], indices[0]];
+        assert_eq!(contract.transfer_many(twice, accounts.charlie), Err(Error::NotOwner));
+        assert_eq!(contract.balance_of(accounts.alice), 3);
+
+        let emitted = test::recorded_events().count();
+        assert_eq!(contract.transfer_many(indices.clone(), accounts.charlie), Ok(()));
+        assert_eq!(test::recorded_events().count(), emitted + 3);
+        for index in indices {
+            assert_eq!(contract.owner_of(index), Ok(accounts.charlie));
+        }
+        assert_eq!(contract.balance_of(accounts.alice), 0);
+        assert_eq!(contract.balance_of(accounts.charlie), 3);
+    }
+
+    /// Tests the `get_nfts` function to ensure several NFTs can be fetched at once.
+    /// - Verifies that existing NFTs are returned and missing ones are `None`, in input order.
+    /// - Verifies that oversized requests fail with `Error::TooManyItems`.
+    #[ink::test]
+    fn test_get_nfts() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+
+        let nfts = contract.get_nfts(vec![indices[1], 42, indices[0]]).unwrap();
+        assert_eq!(nfts.len(), 3);
+        assert_eq!(nfts[0], contract.get_nft(indices[1]));
+        assert_eq!(nfts[1], None);
+        assert_eq!(nfts[2].as_ref().unwrap().token_name(), "NFT #1");
+        assert_eq!(
+            contract.get_nfts(vec![indices[0]; MAX_LOOKUP_SIZE + 1]),
+            Err(Error::TooManyItems)
+        );
+    }
+
+    /// Tests that `burn` emits a dedicated `Burned` event.
+    /// - Verifies that the `Burned` event carries the index and the owner.
+    /// - Verifies that it is followed by the `Transfer` event to the zero account.
+    #[ink::test]
+    fn test_burned_event() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        contract.burn(token_index).unwrap();
+
+        let events: Vec<_> = test::recorded_events().collect();
+        let data = &events[events.len() - 2].data;
+        let burned: Burned = scale::Decode::decode(&mut &data[..]).unwrap();
+        assert_eq!(burned, Burned { index: token_index, owner: accounts.alice });
+        assert_eq!(last_event::<Transfer>().to, AccountId::from([0u8; 32]));
+    }
+
+    /// Tests that `setup_oracle` can't be repeated after an admin handover.
+    /// - Verifies that the new admin's `setup_oracle` call fails with `Error::OracleAlreadySet`.
+    /// - Verifies that the oracle counter is not reset by the rejected call.
+    #[ink::test]
+    fn test_setup_oracle_after_admin_handover() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+        contract.transfer_admin(accounts.bob).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.setup_oracle(), Err(Error::OracleAlreadySet));
+        assert_eq!(contract.get_oracle_data().current_index, 1);
+    }
+
+    /// Tests the `set_observer` function to ensure only the admin can configure the observer.
+    /// - Verifies that a non-admin cannot set the observer.
+    /// - Verifies that the observer can be set and cleared again.
+    #[ink::test]
+    fn test_set_observer() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.observer(), None);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_observer(Some(accounts.bob)), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_observer(Some(accounts.django)).unwrap();
+        assert_eq!(contract.observer(), Some(accounts.django));
+        contract.set_observer(None).unwrap();
+        assert_eq!(contract.observer(), None);
+    }
+
+    /// Tests the `set_edition_supply` and `mint_edition` functions.
+    /// - Verifies that only the admin can configure an edition.
+    /// - Verifies that minted NFTs carry the edition in their name and decrement its supply.
+    /// - Verifies that an exhausted or unconfigured edition fails with `Error::EditionSoldOut`.
+    #[ink::test]
+    fn test_mint_edition() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_edition_supply(7, 2), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_edition_supply(7, 2).unwrap();
+        let first = contract.mint_edition(7).unwrap();
+        assert_eq!(contract.get_nft(first).unwrap().token_name(), "NFT #1 (Edition 7)");
+        assert_eq!(contract.edition_remaining(7), 1);
+        contract.mint_edition(7).unwrap();
+        assert_eq!(contract.edition_remaining(7), 0);
+
+        assert_eq!(contract.mint_edition(7), Err(Error::EditionSoldOut));
+        assert_eq!(contract.mint_edition(8), Err(Error::EditionSoldOut));
+        assert_eq!(contract.total_supply(), 2);
+    }
+
+    /// Tests that the lazily stored string fields behave like plain fields.
+    /// - Verifies that a fresh contract starts from the defaults, even after another one was
+    ///   configured.
+    /// - Verifies that the prefix, URIs and collection labels round-trip through storage.
+    #[ink::test]
+    fn test_lazy_string_fields() {
+        let mut contract = NFTMintingMachine::new_with_prefix("Gem #".into()).unwrap();
+        contract.setup_oracle().unwrap();
+        contract.set_contract_uri("ipfs://collection.json".into()).unwrap();
+        contract.set_placeholder_uri("ipfs://hidden".into()).unwrap();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "Gem #1");
+        assert_eq!(contract.token_uri(token_index), Ok("ipfs://hidden".into()));
+
+        contract.set_base_uri("ipfs://gems/".into()).unwrap();
+        contract.reveal().unwrap();
+        assert_eq!(contract.token_uri(token_index), Ok("ipfs://gems/1.json".into()));
+        assert_eq!(contract.contract_uri(), "ipfs://collection.json");
+
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "NFT #1");
+        assert_eq!(contract.token_uri(token_index), Ok(String::new()));
+        assert_eq!(contract.contract_uri(), "");
+        assert_eq!(contract.name(), "");
+    }
+
+    /// Tests the `admin` function to ensure clients can discover the admin.
+    /// - Verifies that the admin is the constructor caller.
+    /// - Verifies that it follows an admin handover.
+    #[ink::test]
+    fn test_admin() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        let mut contract = NFTMintingMachine::new();
+        assert_eq!(contract.admin(), accounts.eve);
+
+        contract.transfer_admin(accounts.bob).unwrap();
+        assert_eq!(contract.admin(), accounts.bob);
+    }
+
+    /// Tests the `is_oracle_setup` function to ensure the setup state is exposed.
+    /// - Verifies that it is `false` before and `true` after `setup_oracle`.
+    #[ink::test]
+    fn test_is_oracle_setup() {
+        let mut contract = NFTMintingMachine::new();
+        assert!(!contract.is_oracle_setup());
+        contract.setup_oracle().unwrap();
+        assert!(contract.is_oracle_setup());
+    }
+
+    /// Tests the `set_token_royalty` function to ensure single NFTs can override the royalty.
+    /// - Verifies that a token royalty takes precedence over the collection default.
+    /// - Verifies that other NFTs keep using the default.
+    /// - Verifies that non-admins, rates above 10000 bps and missing NFTs are rejected.
+    #[ink::test]
+    fn test_set_token_royalty() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let mut contract = NFTMintingMachine::new_with_royalty(accounts.bob, 500).unwrap();
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(2).unwrap();
+
+        assert_eq!(
+            contract.set_token_royalty(indices[0], accounts.charlie, 10_001),
+            Err(Error::InvalidRoyalty)
+        );
+        assert_eq!(
+            contract.set_token_royalty(99, accounts.charlie, 1_000),
+            Err(Error::NFTNotFound)
+        );
+        contract.set_token_royalty(indices[0], accounts.charlie, 1_000).unwrap();
+
+        assert_eq!(contract.royalty_info(indices[0], 10_000), (accounts.charlie, 1_000));
+        assert_eq!(contract.royalty_info(indices[1], 10_000), (accounts.bob, 500));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.set_token_royalty(indices[1], accounts.bob, 9_000),
+            Err(Error::NotAdmin)
+        );
+    }
+
+    /// Tests the `lock` and `unlock` functions to ensure NFTs can be held in place.
+    /// - Verifies that only the admin or a `ROLE_LOCKER` can lock NFTs.
+    /// - Verifies that a locked NFT can't be transferred or burned.
+    /// - Verifies that unlocking restores transferability.
+    #[ink::test]
+    fn test_lock_and_unlock() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_to(accounts.bob).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.lock(token_index), Err(Error::NotAdmin));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.grant_role(accounts.charlie, ROLE_LOCKER).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.lock(token_index + 1), Err(Error::NFTNotFound));
+        contract.lock(token_index).unwrap();
+        assert!(contract.is_locked(token_index));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_nft(token_index, accounts.django), Err(Error::TokenLocked));
+        assert_eq!(contract.burn(token_index), Err(Error::TokenLocked));
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        contract.unlock(token_index).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_nft(token_index, accounts.django), Ok(()));
+    }
+
+    /// Tests the `set_attribute` and `get_attribute` functions.
+    /// - Verifies that the owner and the admin can set traits that read back per key.
+    /// - Verifies that a non-owner is rejected.
+    /// - Verifies that traits can't be changed once metadata is frozen.
+    #[ink::test]
+    fn test_attributes() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_to(accounts.bob).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.set_attribute(token_index, "color".into(), "red".into()).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_attribute(token_index, "background".into(), "gold".into()).unwrap();
+        assert_eq!(contract.get_attribute(token_index, "color".into()), Some("red".into()));
+        assert_eq!(contract.get_attribute(token_index, "background".into()), Some("gold".into()));
+        assert_eq!(contract.get_attribute(token_index, "size".into()), None);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.set_attribute(token_index, "color".into(), "blue".into()),
+            Err(Error::NotOwner)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.freeze_metadata().unwrap();
+        assert_eq!(
+            contract.set_attribute(token_index, "color".into(), "blue".into()),
+            Err(Error::MetadataFrozen)
+        );
+        assert_eq!(contract.get_attribute(token_index, "color".into()), Some("red".into()));
+    }
+
+    /// Tests the `last_mint` function to ensure the most recent mint is tracked.
+    /// - Verifies that it is `None` before the first mint.
+    /// - Verifies that it reports the newest index and its minter after mints by two accounts.
+    #[ink::test]
+    fn test_last_mint() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.last_mint(), None);
+
+        contract.mint_token().unwrap();
+        assert_eq!(contract.last_mint(), Some((1, accounts.alice)));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.last_mint(), Some((token_index, accounts.bob)));
+    }
+
+    /// Tests the interaction between pausing and the ownership-changing messages.
+    /// - Verifies that `transfer_nft`, `transfer_from`, `transfer_many` and `burn` keep working
+    ///   on legitimately minted NFTs while minting is paused.
+    /// - Verifies that transferring a missing NFT still fails with `Error::NFTNotFound`.
+    #[ink::test]
+    fn test_transfers_while_paused() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.transfer_nft(1, accounts.bob), Err(Error::NFTNotFound));
+        contract.setup_oracle().unwrap();
+        let indices = contract.mint_batch(4).unwrap();
+        contract.pause().unwrap();
+
+        assert_eq!(contract.transfer_nft(indices[0], accounts.bob), Ok(()));
+        contract.approve(indices[1], accounts.bob).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_from(accounts.alice, accounts.bob, indices[1]), Ok(()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.transfer_many(vec![indices[2]], accounts.charlie), Ok(()));
+        assert_eq!(contract.burn(indices[3]), Ok(()));
+        assert_eq!(contract.transfer_nft(indices[3], accounts.bob), Err(Error::NFTNotFound));
+
+        assert_eq!(contract.balance_of(accounts.bob), 2);
+        assert_eq!(contract.balance_of(accounts.charlie), 1);
+        assert_eq!(contract.mint_token(), Err(Error::Paused));
+    }
+
+    /// Tests the `new_with_royalty_cap` constructor to ensure royalty rates are bounded.
+    /// - Verifies that a constructor royalty above the cap fails with `Error::RoyaltyTooHigh`.
+    /// - Verifies that token royalties up to the cap are accepted and above it rejected.
+    #[ink::test]
+    fn test_royalty_cap() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            NFTMintingMachine::new_with_royalty_cap(accounts.bob, 1_500, 1_000).err(),
+            Some(Error::RoyaltyTooHigh)
+        );
+        assert_eq!(
+            NFTMintingMachine::new_with_royalty_cap(accounts.bob, 500, 10_001).err(),
+            Some(Error::InvalidRoyalty)
+        );
+
+        let mut contract =
+            NFTMintingMachine::new_with_royalty_cap(accounts.bob, 500, 1_000).unwrap();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.set_token_royalty(token_index, accounts.charlie, 1_000), Ok(()));
+        assert_eq!(
+            contract.set_token_royalty(token_index, accounts.charlie, 1_001),
+            Err(Error::RoyaltyTooHigh)
+        );
+        assert_eq!(contract.royalty_info(token_index, 10_000), (accounts.charlie, 1_000));
+    }
+
+    /// Tests the `set_mint_block_window` function to ensure minting is limited by block number.
+    /// - Verifies that only the admin can set the window.
+    /// - Verifies that minting fails with `Error::MintNotActive` before and after the window.
+    /// - Verifies that the timestamp window must also be open.
+    #[ink::test]
+    fn test_mint_block_window() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_mint_block_window(Some(10), Some(20)), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_mint_block_window(Some(10), Some(20)), Ok(()));
+
+        test::set_block_number::<DefaultEnvironment>(9);
+        assert_eq!(contract.mint_token(), Err(Error::MintNotActive));
+        test::set_block_number::<DefaultEnvironment>(10);
+        assert_eq!(contract.mint_token(), Ok(1));
+        test::set_block_number::<DefaultEnvironment>(19);
+        assert_eq!(contract.mint_token(), Ok(2));
+        test::set_block_number::<DefaultEnvironment>(20);
+        assert_eq!(contract.mint_token(), Err(Error::MintNotActive));
+
+        test::set_block_number::<DefaultEnvironment>(15);
+        test::set_block_timestamp::<DefaultEnvironment>(500);
+        assert_eq!(contract.set_mint_window(Some(1_000), None), Ok(()));
+        assert_eq!(contract.mint_token(), Err(Error::MintNotActive));
+        test::set_block_timestamp::<DefaultEnvironment>(1_000);
+        assert_eq!(contract.mint_token(), Ok(3));
+    }
+
+    /// Tests the `setup_oracle_from` function to ensure numbering can continue from an offset.
+    /// - Verifies that only the admin can set up the oracle.
+    /// - Verifies that the first mint receives index `start + 1`, or `start` when zero-based.
+    /// - Verifies that a second setup fails with `Error::OracleAlreadySet`.
+    #[ink::test]
+    fn test_setup_oracle_from() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.setup_oracle_from(100), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.setup_oracle_from(100), Ok(()));
+        assert_eq!(contract.mint_token(), Ok(101));
+        assert_eq!(contract.get_oracle_data().current_index, 101);
+
+        assert_eq!(contract.setup_oracle_from(0), Err(Error::OracleAlreadySet));
+        assert_eq!(contract.setup_oracle(), Err(Error::OracleAlreadySet));
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_start_index(0);
+        assert_eq!(contract.setup_oracle_from(100), Ok(()));
+        assert_eq!(contract.mint_token(), Ok(100));
+    }
+
+    /// Tests the `transfer_count` function to ensure each change of hands is recorded.
+    /// - Verifies that a freshly minted NFT has a count of 0.
+    /// - Verifies that the count increments on each transfer, whichever path is used.
+    /// - Verifies that querying a missing NFT fails with `Error::NFTNotFound`.
+    #[ink::test]
+    fn test_transfer_count() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.transfer_count(token_index), Ok(0));
+
+        assert_eq!(contract.transfer_nft(token_index, accounts.bob), Ok(()));
+        assert_eq!(contract.transfer_count(token_index), Ok(1));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_many(vec![token_index], accounts.charlie), Ok(()));
+        assert_eq!(contract.transfer_count(token_index), Ok(2));
+
+        assert_eq!(contract.transfer_count(token_index + 1), Err(Error::NFTNotFound));
+    }
+
+    /// Tests the `admin_transfer` function to ensure the admin can recover NFTs.
+    /// - Verifies that only the admin can force a transfer.
+    /// - Verifies that the admin can move an NFT it doesn't own, even while locked.
+    /// - Verifies that a `Transfer` event is followed by an `AdminForceTransfer` event.
+    #[ink::test]
+    fn test_admin_transfer() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.admin_transfer(token_index, accounts.charlie), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.admin_transfer(token_index, accounts.bob), Ok(()));
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.bob));
+        let events: Vec<_> = test::recorded_events().collect();
+        let data = &events[events.len() - 2].data;
+        let transfer: Transfer = scale::Decode::decode(&mut &data[..]).unwrap();
+        assert_eq!(transfer.to, accounts.bob);
+        assert_eq!(
+            last_event::<AdminForceTransfer>(),
+            AdminForceTransfer { index: token_index, from: accounts.alice, to: accounts.bob }
+        );
+
+        contract.lock(token_index).unwrap();
+        assert_eq!(contract.admin_transfer(token_index, accounts.charlie), Ok(()));
+        assert_eq!(contract.owner_of(token_index), Ok(accounts.charlie));
+        assert_eq!(
+            contract.admin_transfer(token_index + 1, accounts.bob),
+            Err(Error::NFTNotFound)
+        );
+    }
+
+    /// Tests the `collected` function to ensure mint revenue is reported.
+    /// - Verifies that the collected balance grows by the price of a paid mint.
+    /// - Verifies that it shrinks again once the admin withdraws.
+    #[ink::test]
+    fn test_collected() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(accounts.eve);
+        let mut contract = NFTMintingMachine::new_with_price(100);
+        contract.setup_oracle().unwrap();
+        let initial = contract.collected();
+
+        test::transfer_in::<DefaultEnvironment>(100);
+        contract.mint_token().unwrap();
+        assert_eq!(contract.collected(), initial + 100);
+
+        assert_eq!(contract.withdraw(40), Ok(()));
+        assert_eq!(contract.collected(), initial + 60);
+    }
+
+    /// Tests the `new_with_padding` constructor to ensure indices are zero-padded in names.
+    /// - Verifies that a width of 4 produces names like "NFT #0001".
+    /// - Verifies that indices wider than the padding are not truncated.
+    #[ink::test]
+    fn test_pad_width() {
+        let mut contract = NFTMintingMachine::new_with_padding(4).unwrap();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "NFT #0001");
+
+        let mut contract = NFTMintingMachine::new_with_padding(4).unwrap();
+        contract.setup_oracle_from(12_344).unwrap();
+        let token_index = contract.mint_token().unwrap();
+        assert_eq!(contract.get_nft(token_index).unwrap().token_name(), "NFT #12345");
+    }
+
+    /// Tests that `mint_token` refunds any payment above the mint price.
+    /// - Verifies that the caller gets the excess back and the contract keeps the price.
+    #[ink::test]
+    fn test_mint_refund() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(accounts.frank);
+        let mut contract = NFTMintingMachine::new_with_price(100);
+        contract.setup_oracle().unwrap();
+        let caller_balance = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+        let collected = contract.collected();
+
+        test::transfer_in::<DefaultEnvironment>(150);
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+            Ok(caller_balance - 100)
+        );
+        assert_eq!(contract.collected(), collected + 100);
+    }
+
+    /// Tests that the other payable mints refund overpayment like `mint_token`.
+    /// - Verifies that `mint_with_uri`, `mint_with_seed` and `mint_edition` keep one price.
+    /// - Verifies that `mint_batch` keeps the price of the whole batch.
+    #[ink::test]
+    fn test_payable_mints_refund() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(accounts.frank);
+        let mut contract = NFTMintingMachine::new_with_price(100);
+        contract.setup_oracle().unwrap();
+        contract.set_edition_supply(1, 5).unwrap();
+        let caller_balance = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+        let collected = contract.collected();
+
+        test::transfer_in::<DefaultEnvironment>(250);
+        assert_eq!(contract.mint_with_uri("ipfs://a".into()), Ok(1));
+        test::transfer_in::<DefaultEnvironment>(250);
+        assert_eq!(contract.mint_with_seed(), Ok(2));
+        test::transfer_in::<DefaultEnvironment>(250);
+        assert_eq!(contract.mint_edition(1), Ok(3));
+        test::transfer_in::<DefaultEnvironment>(250);
+        assert_eq!(contract.mint_batch(2), Ok(vec![4, 5]));
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+            Ok(caller_balance - 500)
+        );
+        assert_eq!(contract.collected(), collected + 500);
+    }
+
+    /// Tests the `new_with_price_tiers` constructor to ensure the price follows the supply.
+    /// - Verifies that unsorted tiers fail with `Error::InvalidPriceTiers`.
+    /// - Verifies that the charged price changes once a tier boundary is crossed.
+    #[ink::test]
+    fn test_price_tiers() {
+        assert_eq!(
+            NFTMintingMachine::new_with_price_tiers(vec![(0, 100), (0, 200)]).err(),
+            Some(Error::InvalidPriceTiers)
+        );
+        assert_eq!(
+            NFTMintingMachine::new_with_price_tiers(vec![(5, 100), (2, 200)]).err(),
+            Some(Error::InvalidPriceTiers)
+        );
+
+        let mut contract =
+            NFTMintingMachine::new_with_price_tiers(vec![(0, 100), (2, 200)]).unwrap();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.current_price(), 100);
+
+        test::set_value_transferred::<DefaultEnvironment>(100);
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.mint_token(), Ok(2));
+        assert_eq!(contract.current_price(), 200);
+        assert_eq!(contract.mint_token(), Err(Error::InsufficientPayment));
+
+        test::set_value_transferred::<DefaultEnvironment>(200);
+        assert_eq!(contract.mint_token(), Ok(3));
+    }
+
+    /// Tests that `mint_batch` prices each NFT at its own tier.
+    /// - Verifies that a batch crossing a tier boundary can't be paid at the starting tier.
+    /// - Verifies that paying the sum of the per-NFT tier prices mints the whole batch.
+    #[ink::test]
+    fn test_batch_crosses_price_tier() {
+        let mut contract =
+            NFTMintingMachine::new_with_price_tiers(vec![(0, 100), (2, 1_000)]).unwrap();
+        contract.setup_oracle().unwrap();
+
+        test::set_value_transferred::<DefaultEnvironment>(500);
+        assert_eq!(contract.mint_batch(5), Err(Error::InsufficientPayment));
+        test::set_value_transferred::<DefaultEnvironment>(3_199);
+        assert_eq!(contract.mint_batch(5), Err(Error::InsufficientPayment));
+        test::set_value_transferred::<DefaultEnvironment>(3_200);
+        assert_eq!(contract.mint_batch(5), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(contract.current_price(), 1_000);
+    }
+
+    /// Tests the `set_cooldown` function to ensure accounts are throttled between mints.
+    /// - Verifies that only the admin can set the cooldown.
+    /// - Verifies that minting again too early fails with `Error::CooldownActive`.
+    /// - Verifies that minting succeeds again once the cooldown has elapsed.
+    /// - Verifies that the cooldown is tracked per account.
+    #[ink::test]
+    fn test_mint_cooldown() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_cooldown(60), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_cooldown(60), Ok(()));
+
+        test::set_block_timestamp::<DefaultEnvironment>(1_000);
+        assert_eq!(contract.mint_token(), Ok(1));
+        test::set_block_timestamp::<DefaultEnvironment>(1_059);
+        assert_eq!(contract.mint_token(), Err(Error::CooldownActive));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.mint_token(), Ok(2));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(1_060);
+        assert_eq!(contract.mint_token(), Ok(3));
+    }
+
+    /// Tests the `mint_status` function to ensure it reflects the active restrictions.
+    /// - Verifies that a paused, sold-out collection reports both conditions.
+    /// - Verifies that the mint window is reported.
+    /// - Verifies that the caller's remaining allowance follows the per-account limit.
+    #[ink::test]
+    fn test_mint_status() {
+        let mut contract = NFTMintingMachine::new_with_cap(2);
+        contract.setup_oracle().unwrap();
+        let open = MintStatus {
+            paused: false,
+            within_window: true,
+            remaining_for_caller: None,
+            sold_out: false,
+        };
+        assert_eq!(contract.mint_status(), open);
+
+        contract.mint_batch(2).unwrap();
+        contract.pause().unwrap();
+        contract.set_mint_window(Some(1_000), None).unwrap();
+        assert_eq!(
+            contract.mint_status(),
+            MintStatus { paused: true, within_window: false, sold_out: true, ..open }
+        );
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_mint_limit(3);
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+        assert_eq!(contract.mint_status().remaining_for_caller, Some(2));
+    }
+
+    /// Tests that revoking an operator with `set_approval_for_all` removes its storage entries.
+    /// - Verifies that approving and then revoking leaves no residual storage cells.
+    /// - Verifies that revoking an operator that was never approved is a no-op.
+    /// - Verifies that the operator can be approved again afterwards.
+    #[ink::test]
+    fn test_revoke_operator_removes_entry() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let callee = test::callee::<DefaultEnvironment>();
+        let cells = test::count_used_storage_cells::<DefaultEnvironment>(&callee).unwrap();
+
+        contract.set_approval_for_all(accounts.bob, true).unwrap();
+        assert_eq!(test::count_used_storage_cells::<DefaultEnvironment>(&callee), Ok(cells + 2));
+        contract.set_approval_for_all(accounts.bob, false).unwrap();
+        assert_eq!(test::count_used_storage_cells::<DefaultEnvironment>(&callee), Ok(cells));
+        assert_eq!(contract.set_approval_for_all(accounts.charlie, false), Ok(()));
+        assert_eq!(test::count_used_storage_cells::<DefaultEnvironment>(&callee), Ok(cells));
+
+        assert_eq!(contract.set_approval_for_all(accounts.bob, true), Ok(()));
+        assert!(contract.is_approved_for_all(accounts.alice, accounts.bob));
+    }
+
+    /// Tests that `approve` and `set_approval_for_all` emit approval events.
+    /// - Verifies that `approve` emits an `Approval` event with the owner, spender and index.
+    /// - Verifies that granting and revoking an operator emit `ApprovalForAll` events.
+    #[ink::test]
+    fn test_approval_events() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+
+        contract.approve(token_index, accounts.bob).unwrap();
+        assert_eq!(
+            last_event::<Approval>(),
+            Approval { owner: accounts.alice, approved: accounts.bob, index: token_index }
+        );
+
+        contract.set_approval_for_all(accounts.charlie, true).unwrap();
+        assert_eq!(
+            last_event::<ApprovalForAll>(),
+            ApprovalForAll { owner: accounts.alice, operator: accounts.charlie, approved: true }
+        );
+        contract.set_approval_for_all(accounts.charlie, false).unwrap();
+        assert_eq!(
+            last_event::<ApprovalForAll>(),
+            ApprovalForAll { owner: accounts.alice, operator: accounts.charlie, approved: false }
+        );
+    }
+
+    /// Tests the `delegated_mint` function to ensure partners mint within their allowance.
+    /// - Verifies that only the admin can grant an allowance.
+    /// - Verifies that each delegated mint goes to the recipient and uses up the allowance.
+    /// - Verifies that minting beyond the allowance fails with `Error::NoAllowance`.
+    #[ink::test]
+    fn test_delegated_mint() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.set_mint_allowance(accounts.bob, 2), Err(Error::NotAdmin));
+        assert_eq!(contract.delegated_mint(accounts.charlie), Err(Error::NoAllowance));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.set_mint_allowance(accounts.bob, 2), Ok(()));
+        assert_eq!(contract.mint_allowance(accounts.bob), 2);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.delegated_mint(accounts.charlie), Ok(1));
+        assert_eq!(contract.owner_of(1), Ok(accounts.charlie));
+        assert_eq!(contract.mint_allowance(accounts.bob), 1);
+        assert_eq!(contract.delegated_mint(accounts.django), Ok(2));
+        assert_eq!(contract.mint_allowance(accounts.bob), 0);
+        assert_eq!(contract.delegated_mint(accounts.charlie), Err(Error::NoAllowance));
+        assert_eq!(contract.total_supply(), 2);
+    }
+
+    /// Tests the `migrate` function.
+    /// - Verifies that unmigrated storage reports version 0.
+    /// - Verifies that only the admin can migrate.
+    /// - Verifies that the first call brings the storage to `STORAGE_VERSION`, keeping
+    ///   existing values.
+    /// - Verifies that a second call fails with `Error::AlreadyMigrated`.
+    #[ink::test]
+    fn test_migrate() {
+        let mut contract = NFTMintingMachine::new_with_collection("Apes".into(), "APE".into());
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.version(), 0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.migrate(), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.migrate(), Ok(()));
+        assert_eq!(contract.version(), STORAGE_VERSION);
+        assert_eq!(contract.name(), "Apes");
+        assert_eq!(contract.migrate(), Err(Error::AlreadyMigrated));
+        assert_eq!(contract.version(), STORAGE_VERSION);
+    }
+
+    /// Tests the `set_code` function to ensure only the admin can upgrade the contract.
+    /// - Verifies that a non-admin fails with `Error::NotAdmin`.
+    /// - The code swap itself is covered by the end-to-end tests, as the off-chain
+    ///   environment doesn't support it.
+    #[ink::test]
+    fn test_set_code_requires_admin() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.set_code([7u8; 32].into()), Err(Error::NotAdmin));
+    }
+
+    /// Tests that the `current_price` quote matches what `mint_token` charges.
+    /// - Verifies at several supply levels that an overpaying caller is charged exactly the quote.
+    #[ink::test]
+    fn test_current_price_matches_charge() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_callee::<DefaultEnvironment>(AccountId::from([0x42; 32]));
+        let mut contract =
+            NFTMintingMachine::new_with_price_tiers(vec![(0, 100), (2, 150), (4, 300)]).unwrap();
+        contract.setup_oracle().unwrap();
+
+        for expected in [100, 100, 150, 150, 300] {
+            let quote = contract.current_price();
+            assert_eq!(quote, expected);
+            let balance = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+            test::transfer_in::<DefaultEnvironment>(500);
+            contract.mint_token().unwrap();
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(accounts.alice),
+                Ok(balance - quote)
+            );
+        }
+    }
+
+    /// Tests the `is_allowlisted` function to ensure allowlist membership can be queried.
+    /// - Verifies that a listed account is reported and an unlisted one is not.
+    /// - Verifies that membership is reported while the allowlist gate is disabled.
+    /// - Verifies that a removed account is no longer reported.
+    #[ink::test]
+    fn test_is_allowlisted() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.add_to_allowlist(accounts.bob).unwrap();
+
+        assert!(contract.is_allowlisted(accounts.bob));
+        assert!(!contract.is_allowlisted(accounts.charlie));
+
+        contract.set_allowlist_only(true).unwrap();
+        assert!(contract.is_allowlisted(accounts.bob));
+        contract.remove_from_allowlist(accounts.bob).unwrap();
+        assert!(!contract.is_allowlisted(accounts.bob));
+    }
+
+    /// Tests the `add_many_to_allowlist` and `remove_many_from_allowlist` functions.
+    /// - Verifies that only the admin can update the allowlist in bulk.
+    /// - Verifies that five added accounts are each listed, and removed ones are not.
+    /// - Verifies that more than `MAX_LOOKUP_SIZE` accounts fail with `Error::TooManyItems`.
+    #[ink::test]
+    fn test_batched_allowlist() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let listed =
+            vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve, accounts.frank];
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.add_many_to_allowlist(listed.clone()), Err(Error::NotAdmin));
+        assert_eq!(contract.remove_many_from_allowlist(listed.clone()), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.add_many_to_allowlist(listed.clone()), Ok(()));
+        assert!(listed.iter().all(|who| contract.is_allowlisted(*who)));
+        assert!(!contract.is_allowlisted(accounts.alice));
+
+        assert_eq!(contract.remove_many_from_allowlist(listed[..2].to_vec()), Ok(()));
+        assert!(!contract.is_allowlisted(accounts.bob));
+        assert!(!contract.is_allowlisted(accounts.charlie));
+        assert!(contract.is_allowlisted(accounts.django));
+
+        let too_many = vec![accounts.bob; MAX_LOOKUP_SIZE + 1];
+        assert_eq!(contract.add_many_to_allowlist(too_many.clone()), Err(Error::TooManyItems));
+        assert_eq!(contract.remove_many_from_allowlist(too_many), Err(Error::TooManyItems));
+    }
+
+    /// Tests the `new_with_platform_fee` constructor to ensure the fee goes to the treasury.
+    /// - Verifies that a zero treasury fails with `Error::ZeroAddress`.
+    /// - Verifies that minting requires the price plus the platform fee.
+    /// - Verifies that the treasury receives the fee and the contract keeps the price.
+    #[ink::test]
+    fn test_platform_fee() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            NFTMintingMachine::new_with_platform_fee(100, 10, AccountId::from([0u8; 32])).err(),
+            Some(Error::ZeroAddress)
+        );
+
+        test::set_callee::<DefaultEnvironment>(accounts.frank);
+        let mut contract =
+            NFTMintingMachine::new_with_platform_fee(100, 10, accounts.django).unwrap();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.current_price(), 110);
+        let treasury = test::get_account_balance::<DefaultEnvironment>(accounts.django).unwrap();
+        let collected = contract.collected();
+
+        test::set_value_transferred::<DefaultEnvironment>(100);
+        assert_eq!(contract.mint_token(), Err(Error::InsufficientPayment));
+
+        test::transfer_in::<DefaultEnvironment>(110);
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.django),
+            Ok(treasury + 10)
+        );
+        assert_eq!(contract.collected(), collected + 100);
+    }
+
+    /// Tests the `holder_count` function to ensure distinct holders are counted.
+    /// - Verifies that minting counts a new holder once, however many NFTs it holds.
+    /// - Verifies that a transfer to a new owner adds a holder and one back removes it.
+    /// - Verifies that a self-transfer leaves the count unchanged.
+    /// - Verifies that burning an account's last NFT removes the holder.
+    #[ink::test]
+    fn test_holder_count() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.holder_count(), 0);
+
+        let first = contract.mint_token().unwrap();
+        let second = contract.mint_token().unwrap();
+        assert_eq!(contract.holder_count(), 1);
+
+        contract.transfer_nft(first, accounts.bob).unwrap();
+        assert_eq!(contract.holder_count(), 2);
+        contract.transfer_nft(second, accounts.alice).unwrap();
+        assert_eq!(contract.holder_count(), 2);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.transfer_nft(first, accounts.alice).unwrap();
+        assert_eq!(contract.holder_count(), 1);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.burn(first).unwrap();
+        assert_eq!(contract.holder_count(), 1);
+        contract.burn(second).unwrap();
+        assert_eq!(contract.holder_count(), 0);
+    }
+
+    /// Tests the ownership checks shared by `transfer_nft`, `approve`, `burn` and `rename`.
+    /// - Verifies that a missing NFT fails with `Error::NFTNotFound` on every path.
+    /// - Verifies that an NFT owned by someone else fails with `Error::NotOwner` on every path.
+    /// - Verifies that the owner passes every check.
+    #[ink::test]
+    fn test_owner_checks() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        let missing = token_index + 1;
+
+        assert_eq!(contract.transfer_nft(missing, accounts.bob), Err(Error::NFTNotFound));
+        assert_eq!(contract.approve(missing, accounts.bob), Err(Error::NFTNotFound));
+        assert_eq!(contract.burn(missing), Err(Error::NFTNotFound));
+        assert_eq!(contract.rename(missing, "Gem".into()), Err(Error::NFTNotFound));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.transfer_nft(token_index, accounts.bob), Err(Error::NotOwner));
+        assert_eq!(contract.approve(token_index, accounts.bob), Err(Error::NotOwner));
+        assert_eq!(contract.burn(token_index), Err(Error::NotOwner));
+        assert_eq!(contract.rename(token_index, "Gem".into()), Err(Error::NotOwner));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.rename(token_index, "Gem".into()), Ok(()));
+        assert_eq!(contract.approve(token_index, accounts.bob), Ok(()));
+        assert_eq!(contract.transfer_nft(token_index, accounts.bob), Ok(()));
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.burn(token_index), Ok(()));
+    }
+
+    /// Tests that generated token names stay within `MAX_NAME_LENGTH`.
+    /// - Verifies that a prefix of `MAX_PREFIX_LENGTH` bytes is accepted and used.
+    /// - Verifies that a longer prefix fails with `Error::NameTooLong`.
+    /// - Verifies that padding wider than any index fails with `Error::NameTooLong`.
+    #[ink::test]
+    fn test_prefix_length_cap() {
+        let prefix = "P".repeat(MAX_PREFIX_LENGTH);
+        let mut contract = NFTMintingMachine::new_with_prefix(prefix.clone()).unwrap();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        let name = contract.get_nft(token_index).unwrap().token_name().clone();
+        assert_eq!(name, prefix + "1");
+        assert!(name.len() <= MAX_NAME_LENGTH);
+
+        let oversized = "P".repeat(MAX_PREFIX_LENGTH + 1);
+        assert_eq!(
+            NFTMintingMachine::new_with_prefix(oversized).err(),
+            Some(Error::NameTooLong)
+        );
+        assert!(NFTMintingMachine::new_with_padding(20).is_ok());
+        assert_eq!(NFTMintingMachine::new_with_padding(21).err(), Some(Error::NameTooLong));
+    }
+
+    /// Tests the `range` function to ensure NFTs can be listed by index range.
+    /// - Verifies that only existing NFTs in `[from, to)` are returned, skipping burned gaps.
+    /// - Verifies that an empty or inverted range returns nothing.
+    /// - Verifies that a range wider than 100 indices fails with `Error::TooManyItems`.
+    #[ink::test]
+    fn test_range() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        contract.mint_batch(6).unwrap();
+        contract.burn(2).unwrap();
+        contract.burn(4).unwrap();
+
+        let indices: Vec<u64> =
+            contract.range(1, 6).unwrap().into_iter().map(|(index, _)| index).collect();
+        assert_eq!(indices, vec![1, 3, 5]);
+        assert_eq!(contract.range(3, 4).unwrap()[0].1.token_name(), "NFT #3");
+        assert_eq!(contract.range(5, 5), Ok(Vec::new()));
+        assert_eq!(contract.range(6, 1), Ok(Vec::new()));
+        assert!(contract.range(0, 100).is_ok());
+        assert_eq!(contract.range(0, 101), Err(Error::TooManyItems));
+    }
+
+    /// Tests the `progress_bps` function to ensure mint progress is reported in basis points.
+    /// - Verifies the progress at 0%, 50% and 100% of the maximum supply.
+    /// - Verifies that a zero cap counts as sold out and an unlimited supply reports `None`.
+    #[ink::test]
+    fn test_progress_bps() {
+        let mut contract = NFTMintingMachine::new_with_cap(4);
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.progress_bps(), Some(0));
+        contract.mint_batch(2).unwrap();
+        assert_eq!(contract.progress_bps(), Some(5_000));
+        contract.mint_batch(2).unwrap();
+        assert_eq!(contract.progress_bps(), Some(10_000));
+
+        assert_eq!(NFTMintingMachine::new_with_cap(0).progress_bps(), Some(10_000));
+        assert_eq!(NFTMintingMachine::new().progress_bps(), None);
+    }
+
+    /// Tests the `increase_max_supply` function to ensure the cap can only be raised.
+    /// - Verifies that only the admin can raise the cap.
+    /// - Verifies that raising the cap allows further mints.
+    /// - Verifies that lowering or keeping the cap fails with `Error::InvalidCap`.
+    /// - Verifies that an unlimited supply cannot be capped this way.
+    #[ink::test]
+    fn test_increase_max_supply() {
+        let mut contract = NFTMintingMachine::new_with_cap(2);
+        contract.setup_oracle().unwrap();
+        contract.mint_batch(2).unwrap();
+        assert_eq!(contract.mint_token(), Err(Error::MaxSupplyReached));
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.increase_max_supply(3), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.increase_max_supply(3), Ok(()));
+        assert_eq!(contract.get_oracle_data().max_supply, Some(3));
+        assert_eq!(contract.mint_token(), Ok(3));
+        assert_eq!(contract.increase_max_supply(3), Err(Error::InvalidCap));
+        assert_eq!(contract.increase_max_supply(1), Err(Error::InvalidCap));
+        assert_eq!(contract.get_oracle_data().max_supply, Some(3));
+
+        let mut unlimited = NFTMintingMachine::new();
+        assert_eq!(unlimited.increase_max_supply(10), Err(Error::InvalidCap));
+    }
+
+    /// Tests the `freeze_token` function.
+    /// - Verifies that only the admin can freeze an NFT, and only an existing one.
+    /// - Verifies that a frozen NFT can no longer be renamed or have its traits changed.
+    /// - Verifies that another NFT stays editable.
+    #[ink::test]
+    fn test_freeze_token() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let frozen = contract.mint_token().unwrap();
+        let editable = contract.mint_token().unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.freeze_token(frozen), Err(Error::NotAdmin));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.freeze_token(99), Err(Error::NFTNotFound));
+        assert_eq!(contract.freeze_token(frozen), Ok(()));
+        assert!(contract.is_token_frozen(frozen));
+        assert!(!contract.is_token_frozen(editable));
+
+        assert_eq!(contract.rename(frozen, "Renamed".into()), Err(Error::MetadataFrozen));
+        assert_eq!(
+            contract.set_attribute(frozen, "color".into(), "red".into()),
+            Err(Error::MetadataFrozen)
+        );
+        assert_eq!(contract.get_attribute(frozen, "color".into()), None);
+
+        assert_eq!(contract.rename(editable, "Renamed".into()), Ok(()));
+        assert_eq!(contract.set_attribute(editable, "color".into(), "red".into()), Ok(()));
+        assert_eq!(contract.get_nft(editable).unwrap().token_name(), "Renamed");
+    }
+
+    /// Tests the `can_mint` function to ensure it reflects every blocking condition.
+    /// - Verifies that it is false before setup, while paused and outside the mint window.
+    /// - Verifies that it is false for an account that reached its limit or isn't allowlisted.
+    /// - Verifies that it is false once the collection is sold out.
+    #[ink::test]
+    fn test_can_mint() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let mut contract = NFTMintingMachine::new_with_mint_limit(1);
+        assert!(!contract.can_mint(accounts.alice));
+        contract.setup_oracle().unwrap();
+        assert!(contract.can_mint(accounts.alice));
+
+        contract.pause().unwrap();
+        assert!(!contract.can_mint(accounts.alice));
+        contract.unpause().unwrap();
+        contract.set_mint_window(Some(1_000), None).unwrap();
+        assert!(!contract.can_mint(accounts.alice));
+        contract.set_mint_window(None, None).unwrap();
+
+        contract.mint_token().unwrap();
+        assert!(!contract.can_mint(accounts.alice));
+        assert!(contract.can_mint(accounts.bob));
+
+        contract.set_allowlist_only(true).unwrap();
+        assert!(!contract.can_mint(accounts.bob));
+        contract.add_to_allowlist(accounts.bob).unwrap();
+        assert!(contract.can_mint(accounts.bob));
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_cap(1);
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+        assert!(!contract.can_mint(accounts.bob));
+    }
+
+    /// Tests the `new_with_start_index` constructor to ensure numbering starts at the offset.
+    /// - Verifies that with a start index of 0 the first mint is index 0 and the next is 1.
+    /// - Verifies that minting past the largest index fails with `Error::CounterOverflow`.
+    #[ink::test]
+    fn test_start_index() {
+        assert_eq!(NFTMintingMachine::new().start_index(), 1);
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let mut contract = NFTMintingMachine::new_with_start_index(0);
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_token(), Ok(0));
+        assert_eq!(contract.owner_of(0), Ok(accounts.alice));
+        assert_eq!(contract.get_nft(0).unwrap().token_name(), "NFT #0");
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.tokens_paginated(0, 10).0.len(), 2);
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_start_index(u64::MAX);
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_token(), Ok(u64::MAX));
+        assert_eq!(contract.mint_token(), Err(Error::CounterOverflow));
+        assert_eq!(contract.total_supply(), 1);
+    }
+
+    /// Tests the `revoke_all_approvals` function.
+    /// - Verifies that every operator the caller approved is revoked in one call.
+    /// - Verifies that operators approved by other owners are unaffected.
+    /// - Verifies that no more than `MAX_OPERATORS` operators can be approved.
+    #[ink::test]
+    fn test_revoke_all_approvals() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.set_approval_for_all(accounts.bob, true).unwrap();
+        contract.set_approval_for_all(accounts.charlie, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        contract.set_approval_for_all(accounts.bob, true).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.revoke_all_approvals(), Ok(()));
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.charlie));
+        assert!(contract.is_approved_for_all(accounts.django, accounts.bob));
+        assert_eq!(
+            last_event::<ApprovalForAll>(),
+            ApprovalForAll {
+                owner: accounts.alice,
+                operator: accounts.charlie,
+                approved: false
+            }
+        );
+
+        for seed in 0..MAX_OPERATORS as u8 {
+            let operator = AccountId::from([seed.saturating_add(100); 32]);
+            assert_eq!(contract.set_approval_for_all(operator, true), Ok(()));
+        }
+        assert_eq!(contract.set_approval_for_all(accounts.bob, true), Err(Error::TooManyItems));
+        assert!(!contract.is_approved_for_all(accounts.alice, accounts.bob));
+    }
+
+    /// Tests the `mint_with_seed` function.
+    /// - Verifies that each NFT gets one of the `RARITY_WEIGHTS` tiers, stored as its
+    ///   `RARITY_ATTRIBUTE` trait, which `set_attribute` cannot change.
+    /// - Verifies that the same inputs produce the same tier on another contract.
+    /// - Verifies that NFTs minted otherwise have no tier and burning clears it.
+    #[ink::test]
+    fn test_mint_with_seed() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_block_timestamp::<DefaultEnvironment>(1_234);
+
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let first = contract.mint_with_seed().unwrap();
+        let second = contract.mint_with_seed().unwrap();
+        for index in [first, second] {
+            let tier = contract.rarity(index).unwrap();
+            assert!(usize::from(tier) < RARITY_WEIGHTS.len());
+            assert_eq!(
+                contract.get_attribute(index, RARITY_ATTRIBUTE.into()),
+                Some(tier.to_string())
+            );
+        }
+        assert_eq!(
+            contract.set_attribute(first, RARITY_ATTRIBUTE.into(), "3".into()),
+            Err(Error::MetadataFrozen)
+        );
+        let plain = contract.mint_token().unwrap();
+        assert_eq!(contract.rarity(plain), None);
+        assert_eq!(
+            contract.set_attribute(plain, RARITY_ATTRIBUTE.into(), "3".into()),
+            Err(Error::MetadataFrozen)
+        );
+        contract.burn(first).unwrap();
+        assert_eq!(contract.rarity(first), None);
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut other = NFTMintingMachine::new();
+        other.setup_oracle().unwrap();
+        other.mint_with_seed().unwrap();
+        assert_eq!(other.rarity(second), None);
+        let replayed = other.mint_with_seed().unwrap();
+        assert_eq!(replayed, second);
+        assert_eq!(other.rarity(replayed), contract.rarity(second));
+    }
+
+    /// Tests the accessors of `Nft` returned by `get_nft`.
+    /// - Verifies the token name, owner, metadata URI, mint timestamp and transfer count.
+    #[ink::test]
+    fn test_nft_accessors() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(4_321);
+        let token_index = contract.mint_with_uri("ipfs://token".into()).unwrap();
+
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.transfer_nft(token_index, accounts.bob).unwrap();
+        let nft = contract.get_nft(token_index).unwrap();
+        assert_eq!(nft.token_name(), "NFT #1");
+        assert_eq!(*nft.owner(), accounts.bob);
+        assert_eq!(nft.metadata_uri(), "ipfs://token");
+        assert_eq!(nft.minted_at(), 4_321);
+        assert_eq!(nft.transfer_count(), 1);
+
+        let plain = contract.mint_token().unwrap();
+        let nft = contract.get_nft(plain).unwrap();
+        assert!(nft.metadata_uri().is_empty());
+        assert_eq!(nft.transfer_count(), 0);
+    }
+
+    /// Tests that hitting the supply cap and overflowing the counter are reported separately.
+    /// - Verifies that a sold-out collection fails with `Error::MaxSupplyReached`, even when
+    ///   its cap is `u64::MAX`.
+    /// - Verifies that an uncapped counter at `u64::MAX` fails with `Error::CounterOverflow`.
+    #[ink::test]
+    fn test_sold_out_vs_overflow() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let mut contract = NFTMintingMachine::new_with_cap(1);
+        contract.setup_oracle().unwrap();
+        contract.mint_token().unwrap();
+        assert_eq!(contract.mint_token(), Err(Error::MaxSupplyReached));
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_cap(u64::MAX);
+        contract.setup_oracle_from(u64::MAX).unwrap();
+        assert_eq!(contract.mint_token(), Err(Error::MaxSupplyReached));
+
+        test::set_callee::<DefaultEnvironment>(accounts.eve);
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle_from(u64::MAX).unwrap();
+        assert_eq!(contract.mint_token(), Err(Error::CounterOverflow));
+        assert_eq!(contract.mint_batch(1), Err(Error::CounterOverflow));
+    }
+
+    /// Tests that names generated by `mint_edition` and `combine` respect `MAX_NAME_LENGTH`.
+    /// - Verifies that an over-long edition name fails with `Error::NameTooLong` and mints
+    ///   nothing.
+    /// - Verifies that an over-long combined name fails with `Error::NameTooLong` and burns
+    ///   nothing.
+    #[ink::test]
+    fn test_generated_name_length() {
+        let prefix = "P".repeat(MAX_PREFIX_LENGTH);
+        let mut contract = NFTMintingMachine::new_with_prefix(prefix).unwrap();
+        contract.setup_oracle_from(u64::MAX - 10).unwrap();
+        contract.set_edition_supply(u32::MAX, 1).unwrap();
+        assert_eq!(contract.mint_edition(u32::MAX), Err(Error::NameTooLong));
+        assert_eq!(contract.edition_remaining(u32::MAX), 1);
+        assert_eq!(contract.total_supply(), 0);
+
+        let a = contract.mint_token().unwrap();
+        let b = contract.mint_token().unwrap();
+        contract.rename(a, "A".repeat(MAX_NAME_LENGTH)).unwrap();
+        assert_eq!(contract.combine(a, b), Err(Error::NameTooLong));
+        assert!(contract.exists(a));
+        assert!(contract.exists(b));
+        contract.rename(a, "A".into()).unwrap();
+        assert!(contract.combine(a, b).is_ok());
+    }
+
+    /// Tests that burning an NFT clears its per-token metadata state.
+    /// - Verifies that a reused index starts unfrozen and without the old traits.
+    /// - Verifies that an NFT can't have more than `MAX_ATTRIBUTES` traits, while existing
+    ///   traits can still be overwritten.
+    #[ink::test]
+    fn test_burn_clears_token_metadata() {
+        let mut contract = NFTMintingMachine::new();
+        contract.setup_oracle().unwrap();
+        let token_index = contract.mint_token().unwrap();
+        contract.set_attribute(token_index, "color".into(), "red".into()).unwrap();
+        contract.freeze_token(token_index).unwrap();
+        contract.burn(token_index).unwrap();
+
+        contract.reset_oracle(0).unwrap();
+        assert_eq!(contract.mint_token(), Ok(token_index));
+        assert!(!contract.is_token_frozen(token_index));
+        assert_eq!(contract.get_attribute(token_index, "color".into()), None);
+        assert_eq!(contract.rename(token_index, "Fresh".into()), Ok(()));
+
+        for key in 0..MAX_ATTRIBUTES {
+            contract.set_attribute(token_index, key.to_string(), "x".into()).unwrap();
+        }
+        assert_eq!(
+            contract.set_attribute(token_index, "extra".into(), "x".into()),
+            Err(Error::TooManyItems)
+        );
+        assert_eq!(contract.set_attribute(token_index, "0".into(), "y".into()), Ok(()));
+        assert_eq!(contract.get_attribute(token_index, "0".into()), Some("y".into()));
+    }
+
+    /// Tests that `mint_reserved` with a count of zero mints nothing.
+    /// - Verifies that no NFT is created with or without a reserve.
+    /// - Verifies that the next public mint gets a fresh index and the ledger stays consistent.
+    #[ink::test]
+    fn test_mint_reserved_zero_count() {
+        let mut contract = NFTMintingMachine::new();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_reserved(accounts.bob, 0), Ok(vec![]));
+        assert_eq!(contract.total_supply(), 0);
+        assert_eq!(contract.balance_of(accounts.bob), 0);
+
+        assert_eq!(contract.mint_token(), Ok(1));
+        assert_eq!(contract.total_supply(), 1);
+        assert_eq!(contract.tokens_of_owner(accounts.bob), Vec::<u64>::new());
+
+        test::set_callee::<DefaultEnvironment>(accounts.django);
+        let mut contract = NFTMintingMachine::new_with_reserve(2);
+        contract.setup_oracle().unwrap();
+        assert_eq!(contract.mint_reserved(accounts.bob, 0), Ok(vec![]));
+        assert_eq!(contract.reserved_remaining(), 2);
+        assert_eq!(contract.mint_reserved(accounts.bob, 2), Ok(vec![1, 2]));
     }
 }